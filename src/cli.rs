@@ -1,10 +1,29 @@
-use clap::{Parser, Subcommand};
+use crate::core::OnConflict;
+use crate::i18n::Language;
+use crate::output::OutputFormat;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "git-switcher")]
 #[command(about = "Git 사용자 프로필 전환 도구")]
 #[command(version = "0.1.0")]
 pub struct Cli {
+    /// 출력 형식 (json이면 결과를 stdout에 JSON으로, 진행 이벤트는 stderr에 한 줄씩 JSON으로 출력)
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+    /// 표시 언어 (기본값: 설정 파일의 language, 없으면 LC_ALL/LC_MESSAGES/LANG)
+    #[arg(long, global = true, value_enum)]
+    pub lang: Option<Language>,
+    /// 사용할 설정 파일 (기본값: GIT_SWITCHER_CONFIG, 없으면 $XDG_CONFIG_HOME/git-switcher/config.toml)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+    /// 경고와 오류만 출력
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+    /// 실행한 git 명령 등 상세 진행 상황까지 출력
+    #[arg(short, long, global = true)]
+    pub verbose: bool,
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -13,34 +32,182 @@ pub struct Cli {
 pub enum Commands {
     /// 프로필을 현재 저장소에 적용
     Use {
-        /// 적용할 프로필 이름
-        profile_name: String,
+        /// 적용할 프로필 이름 (생략하면 목록에서 선택)
+        profile_name: Option<String>,
         /// SSH 연동 활성화
         #[arg(long)]
         ssh: bool,
+        /// extensions.worktreeConfig를 켜고 현재 작업 트리에만 적용
+        #[arg(long)]
+        per_worktree: bool,
+        /// 하위 모듈에도 적용 (원격 주소 매핑이 있으면 그 프로필)
+        #[arg(long)]
+        recurse_submodules: bool,
     },
     /// 현재 적용된 프로필 확인
     Show,
+    /// 실제 적용된 신원과 기대하는 프로필 비교 (OK=0, MISMATCH=3, UNMAPPED=4로 종료)
+    Status,
+    /// 현재 저장소의 실제 설정이 팀 정책을 만족하는지 확인 (위반 시 13으로 종료)
+    Verify,
+    /// 저장소에 기대하는 프로필과 작성자/커미터 이메일이 다른 커밋 찾기 (발견하면 3으로 종료)
+    Audit {
+        /// 확인할 커밋 범위 (`origin/main..HEAD` 등, 기본값: 원격 추적 브랜치에 없는 커밋)
+        range: Option<String>,
+        /// 기대하는 프로필 (기본값: 경로 매핑)
+        #[arg(long)]
+        profile: Option<String>,
+    },
+    /// push하지 않은 커밋 중 다른 신원의 커밋을 프로필의 신원으로 다시 쓰기
+    FixAuthors {
+        /// 이 커밋 이후(`<base>..HEAD`)를 다시 씀 (기본값: 원격 추적 브랜치에 없는 커밋)
+        #[arg(long)]
+        base: Option<String>,
+        /// 사용할 프로필 (기본값: 경로 매핑)
+        #[arg(long)]
+        profile: Option<String>,
+        /// 다시 쓴 커밋을 프로필의 서명 키로 서명
+        #[arg(long)]
+        sign: bool,
+        /// 이미 push한 커밋도 다시 씀 (강제 push 필요)
+        #[arg(long)]
+        force: bool,
+        /// 확인 없이 바로 다시 씀
+        #[arg(short, long)]
+        yes: bool,
+        /// 다시 쓸 커밋만 출력
+        #[arg(long, conflicts_with = "yes")]
+        dry_run: bool,
+    },
+    /// 프로필 적용, 크리덴셜 저장/삭제, 정책 확인 기록 조회
+    Log {
+        /// 이 경로 아래 저장소의 기록만 (경로 없이 쓰면 현재 저장소)
+        #[arg(long, value_name = "PATH", num_args = 0..=1)]
+        repo: Option<Option<String>>,
+        /// 이 프로필의 기록만
+        #[arg(long)]
+        profile: Option<String>,
+        /// 이 시각 이후의 기록만 (`7d`, `12h`, `2026-10-01`, `2026-10-01T09:00:00Z`, UTC 기준)
+        #[arg(long)]
+        since: Option<String>,
+    },
+    /// 경로에 매핑된 프로필 확인
+    Which {
+        /// 확인할 경로 (기본값: 현재 디렉토리)
+        path: Option<String>,
+    },
     /// 사용 가능한 프로필 목록
     List,
+    /// 프로필 상세 정보 확인
+    ShowProfile {
+        /// 확인할 프로필 이름
+        name: String,
+        /// extends와 [defaults]를 병합한 결과와 각 값의 출처 표시
+        #[arg(long)]
+        resolved: bool,
+    },
     /// 초기 설정 파일 생성
-    Init,
+    Init {
+        /// 예시 프로필 대신 대화형으로 프로필 생성
+        #[arg(long)]
+        interactive: bool,
+    },
+    /// 기존 Git 설정(~/.gitconfig, includeIf, 저장소 로컬 설정, ~/.ssh/config)에서 프로필과 경로 매핑 가져오기
+    Import {
+        /// 저장소를 찾아볼 작업 디렉토리 (여러 번 지정 가능, 기본값: ~/work, ~/projects 등)
+        #[arg(long = "root", value_name = "DIR")]
+        roots: Vec<String>,
+        /// 확인 없이 바로 저장
+        #[arg(short, long)]
+        yes: bool,
+        /// 제안만 출력하고 저장하지 않음
+        #[arg(long, conflicts_with = "yes")]
+        dry_run: bool,
+    },
+    /// 매핑된 프로필의 신원과 인증 정보로 저장소를 복제하고 프로필 적용
+    Clone {
+        /// 복제할 원격 주소
+        url: String,
+        /// 복제할 디렉토리 (기본값: 주소의 저장소 이름)
+        dir: Option<String>,
+        /// 사용할 프로필 (생략하면 원격 주소 매핑, 디렉토리의 경로 매핑 순서로 찾음)
+        #[arg(long)]
+        profile: Option<String>,
+        /// HTTPS 주소도 SSH로 바꿔 프로필의 SSH 키로 복제
+        #[arg(long)]
+        ssh: bool,
+    },
+    /// 저장소 설정을 바꾸지 않고 프로필의 신원과 인증 정보로 명령 하나 실행
+    Exec {
+        /// 사용할 프로필
+        profile: String,
+        /// 실행할 명령 (`--` 뒤에 적음)
+        #[arg(last = true, required = true, value_name = "COMMAND")]
+        command: Vec<String>,
+    },
+    /// 프로필들의 이메일을 하나의 이름/이메일로 묶는 .mailmap 블록 생성 또는 갱신
+    Mailmap {
+        /// 묶을 프로필 (여러 번 지정 가능, 기본값: 모든 프로필)
+        #[arg(long = "profile", value_name = "NAME")]
+        profiles: Vec<String>,
+        /// 기준 이름/이메일로 쓸 프로필 (기본값: 현재 경로에 매핑된 프로필)
+        #[arg(long)]
+        canonical: Option<String>,
+        /// 갱신할 파일 (기본값: 저장소 최상위의 .mailmap)
+        #[arg(long)]
+        file: Option<PathBuf>,
+        /// 결과만 출력하고 저장하지 않음
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// 프로필과 경로 매핑을 다른 컴퓨터로 옮길 번들 파일로 내보내기
+    Export {
+        /// 번들 파일 경로
+        file: PathBuf,
+        /// 내보낼 프로필 (여러 번 지정 가능, 기본값: 전체, 상속하는 부모 프로필 포함)
+        #[arg(long = "profile", value_name = "NAME")]
+        profiles: Vec<String>,
+        /// PAT도 번들 암호로 다시 암호화해서 포함
+        #[arg(long)]
+        include_tokens: bool,
+    },
+    /// export로 만든 번들 파일을 현재 설정에 병합
+    ImportBundle {
+        /// 번들 파일 경로
+        file: PathBuf,
+        /// 같은 이름의 프로필이나 같은 경로의 매핑이 이미 있을 때의 처리
+        #[arg(long, value_enum, default_value_t = OnConflict::Skip)]
+        on_conflict: OnConflict,
+        /// 번들의 PAT는 가져오지 않음 (암호를 묻지 않음)
+        #[arg(long)]
+        skip_tokens: bool,
+        /// 병합 결과만 출력하고 저장하지 않음
+        #[arg(long)]
+        dry_run: bool,
+    },
     /// 자동으로 프로필 감지하여 적용
     Auto {
         /// SSH 연동 활성화
         #[arg(long)]
         ssh: bool,
+        /// extensions.worktreeConfig를 켜고 현재 작업 트리에만 적용
+        #[arg(long)]
+        per_worktree: bool,
+        /// 하위 모듈에도 적용 (원격 주소 매핑이 있으면 그 프로필)
+        #[arg(long)]
+        recurse_submodules: bool,
     },
     /// 프로필 추가
     Add {
         /// 프로필 이름
-        name: String,
+        #[arg(required_unless_present = "interactive")]
+        name: Option<String>,
         /// 사용자 이름
-        #[arg(long)]
-        user_name: String,
+        #[arg(long, required_unless_present = "interactive")]
+        user_name: Option<String>,
         /// 이메일 주소
-        #[arg(long)]
-        email: String,
+        #[arg(long, required_unless_present = "interactive")]
+        email: Option<String>,
         /// GitHub 사용자명 (선택사항)
         #[arg(long)]
         github_username: Option<String>,
@@ -53,29 +220,85 @@ pub enum Commands {
         /// SSH 키 경로 (선택사항)
         #[arg(long)]
         ssh_key: Option<String>,
+        /// 같은 이름의 프로필이 있으면 덮어쓰기
+        #[arg(long)]
+        force: bool,
+        /// 각 항목을 대화형으로 입력
+        #[arg(long, conflicts_with_all = ["name", "user_name", "email", "github_username", "github_pat", "signing_key", "ssh_key", "force"])]
+        interactive: bool,
+    },
+    /// 기존 프로필의 일부 값만 수정
+    Edit {
+        /// 수정할 프로필 이름
+        name: String,
+        /// 사용자 이름
+        #[arg(long)]
+        user_name: Option<String>,
+        /// 이메일 주소
+        #[arg(long)]
+        email: Option<String>,
+        /// GitHub 사용자명
+        #[arg(long)]
+        github_username: Option<String>,
+        /// GitHub Personal Access Token
+        #[arg(long)]
+        github_pat: Option<String>,
+        /// GPG 서명 키
+        #[arg(long)]
+        signing_key: Option<String>,
+        /// SSH 키 경로
+        #[arg(long)]
+        ssh_key: Option<String>,
+        /// 상속받을 부모 프로필
+        #[arg(long)]
+        extends: Option<String>,
+        /// 값을 제거할 필드 (여러 번 지정 가능)
+        #[arg(long, value_enum)]
+        unset: Vec<ProfileField>,
+    },
+    /// 프로필 이름 변경 (경로 매핑과 extends 참조도 함께 변경)
+    Rename {
+        /// 현재 프로필 이름
+        old_name: String,
+        /// 새 프로필 이름
+        new_name: String,
     },
     /// 프로필 제거
     Remove {
         /// 제거할 프로필 이름
         name: String,
+        /// 프로필을 가리키는 경로 매핑도 함께 제거
+        #[arg(long)]
+        cascade: bool,
     },
     /// 경로 매핑 추가 (자동 감지용)
     Map {
-        /// 디렉토리 경로
+        /// 디렉토리 경로 (--remote면 원격 주소 패턴)
         path: String,
         /// 매핑할 프로필 이름
         profile: String,
+        /// 경로 대신 원격 주소 패턴(`github.com/acme-corp/*`)으로 매핑 (clone에서 사용)
+        #[arg(long)]
+        remote: bool,
     },
     /// 경로 매핑 제거
     Unmap {
-        /// 제거할 디렉토리 경로
+        /// 제거할 디렉토리 경로 (--remote면 원격 주소 패턴)
         path: String,
+        /// 원격 주소 매핑 제거
+        #[arg(long)]
+        remote: bool,
     },
     /// 크리덴셜 관리
     Credentials {
         #[command(subcommand)]
         action: CredentialAction,
     },
+    /// 설정 파일 관리
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
 }
 
 #[derive(Subcommand)]
@@ -90,3 +313,33 @@ pub enum CredentialAction {
     /// 모든 GitHub 계정의 크리덴셜 삭제
     ClearAll,
 }
+
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    /// 오래된 설정 파일을 현재 스키마 버전으로 변환 (원본은 백업)
+    Migrate {
+        /// 변환하지 않고 필요 여부만 확인 (필요하면 종료 코드 14)
+        #[arg(long)]
+        check: bool,
+    },
+    /// 시스템/사용자/저장소 레이어를 병합한 설정 값과 각 값의 출처 레이어 표시
+    Show,
+    /// 설정 파일의 문제(끊어진 매핑, 잘못된 이메일, 없는 SSH 키 등)를 위치와 함께 보고
+    Validate {
+        /// 안전하게 고칠 수 있는 문제를 자동으로 수정
+        #[arg(long)]
+        fix: bool,
+    },
+}
+
+/// `edit --unset`으로 제거할 수 있는 프로필 필드
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ProfileField {
+    UserName,
+    Email,
+    GithubUsername,
+    GithubPat,
+    SigningKey,
+    SshKey,
+    Extends,
+}
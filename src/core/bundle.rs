@@ -0,0 +1,415 @@
+use crate::core::config::write_atomic;
+use crate::core::{Config, Error, Profile, Result};
+use crate::utils::crypto::{PASSPHRASE_ITERATIONS, PassphraseKey, TokenCrypto};
+use crate::{report, t};
+use base64::{Engine as _, engine::general_purpose};
+use clap::{ValueEnum, builder::PossibleValue};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+/// 번들 파일임을 나타내는 `format` 값
+const BUNDLE_FORMAT: &str = "git-switcher-bundle";
+
+/// 이 빌드가 만들고 읽는 번들 형식 버전
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// 암호가 맞는지 PAT보다 먼저 확인하려고 함께 암호화해 두는 값
+const PASSPHRASE_CHECK: &str = BUNDLE_FORMAT;
+
+/// 새 컴퓨터로 옮기는 프로필과 경로 매핑 묶음 (`git-switcher export`)
+///
+/// PAT는 빌드마다 다른 키 대신 번들 암호에서 유도한 키로 다시 암호화해서 담음
+#[derive(Deserialize, Serialize)]
+pub struct Bundle {
+    pub format: String,
+    pub version: u32,
+    /// PAT를 포함했을 때만 있음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<BundleEncryption>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+    #[serde(default)]
+    pub path_mappings: BTreeMap<String, String>,
+}
+
+/// 번들 암호에서 키를 유도하는 방법
+#[derive(Deserialize, Serialize)]
+pub struct BundleEncryption {
+    /// 현재는 `pbkdf2-sha256`만 지원
+    pub kdf: String,
+    pub iterations: u32,
+    /// base64
+    pub salt: String,
+    /// `PASSPHRASE_CHECK`를 같은 키로 암호화한 값
+    pub check: String,
+}
+
+/// 같은 이름의 프로필이나 같은 경로의 매핑이 이미 있을 때의 처리
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OnConflict {
+    /// 기존 값을 유지
+    #[default]
+    Skip,
+    /// 번들의 값으로 덮어씀
+    Overwrite,
+    /// 번들의 프로필을 `<이름>-imported`로 추가 (경로 매핑은 기존 값 유지)
+    Rename,
+}
+
+// 값별 도움말도 표시 언어를 따르도록 derive 대신 카탈로그(`cli.import-bundle.on_conflict.<값>`)에서 조회
+impl ValueEnum for OnConflict {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[OnConflict::Skip, OnConflict::Overwrite, OnConflict::Rename]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        let value = match self {
+            OnConflict::Skip => {
+                PossibleValue::new("skip").help(t!("cli.import-bundle.on_conflict.skip"))
+            }
+            OnConflict::Overwrite => {
+                PossibleValue::new("overwrite").help(t!("cli.import-bundle.on_conflict.overwrite"))
+            }
+            OnConflict::Rename => {
+                PossibleValue::new("rename").help(t!("cli.import-bundle.on_conflict.rename"))
+            }
+        };
+        Some(value)
+    }
+}
+
+/// 번들 항목 하나를 병합한 결과
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeOutcome {
+    Added,
+    /// 이미 같은 내용이 있음
+    Unchanged,
+    Skipped,
+    Overwritten,
+    Renamed,
+}
+
+/// 병합한 프로필이나 경로 매핑 하나
+#[derive(Serialize)]
+pub struct MergedEntry {
+    /// 번들에서의 프로필 이름이나 매핑 경로
+    pub name: String,
+    /// 설정에 저장된 프로필 이름 (`rename`이면 바뀐 이름, 매핑이면 가리키는 프로필)
+    pub target: String,
+    pub outcome: MergeOutcome,
+}
+
+/// `git-switcher import-bundle` 결과
+#[derive(Serialize)]
+pub struct MergeReport {
+    pub profiles: Vec<MergedEntry>,
+    pub path_mappings: Vec<MergedEntry>,
+    /// 저장한 프로필 중 PAT를 함께 가져온 수
+    pub tokens: usize,
+    /// 저장한 프로필 중 `--skip-tokens`로 PAT를 가져오지 않은 수
+    pub tokens_skipped: usize,
+}
+
+impl MergeReport {
+    /// 설정이 바뀌는 항목이 있는지
+    pub fn has_changes(&self) -> bool {
+        self.profiles
+            .iter()
+            .chain(&self.path_mappings)
+            .any(|entry| {
+                !matches!(
+                    entry.outcome,
+                    MergeOutcome::Unchanged | MergeOutcome::Skipped
+                )
+            })
+    }
+}
+
+impl Bundle {
+    /// 설정에서 번들 만들기
+    ///
+    /// `names`가 비어 있으면 사용자 설정의 모든 프로필 (시스템 레이어는 새 컴퓨터에도 있으므로 제외).
+    /// 상속하는 부모 프로필은 항상 함께 담고, `key`가 있으면 PAT를 그 키로 다시 암호화해서 담음
+    pub fn from_config(
+        config: &Config,
+        names: &[String],
+        key: Option<(&PassphraseKey, &[u8])>,
+    ) -> Result<Self> {
+        let mut selected: Vec<String> = if names.is_empty() {
+            config
+                .profiles
+                .keys()
+                .filter(|name| !config.is_system_value(&["profiles", name]))
+                .cloned()
+                .collect()
+        } else {
+            for name in names {
+                config.get_profile(name)?;
+            }
+            names.to_vec()
+        };
+        // 부모 프로필까지 포함 (순환은 resolve 단계에서 검사하므로 방문한 이름에서 멈춤)
+        let mut included: BTreeSet<String> = BTreeSet::new();
+        while let Some(name) = selected.pop() {
+            if !included.insert(name.clone()) {
+                continue;
+            }
+            if let Some(parent) = config.get_profile(&name)?.extends.clone() {
+                selected.push(parent);
+            }
+        }
+
+        let mut profiles = BTreeMap::new();
+        for name in &included {
+            let mut profile = config.get_profile(name)?.clone();
+            profile.encrypted_pat = match (&key, profile.get_decrypted_pat()) {
+                (None, _) | (Some(_), Ok(None)) => None,
+                (Some((key, _)), Ok(Some(pat))) => {
+                    Some(TokenCrypto::encrypt_with_passphrase(&pat, key)?)
+                }
+                (Some(_), Err(e)) => {
+                    report!(
+                        Warning,
+                        "{}",
+                        t!("bundle.token_unreadable", name, format!("{:#}", e))
+                    );
+                    None
+                }
+            };
+            profiles.insert(name.clone(), profile);
+        }
+
+        let path_mappings = config
+            .path_mappings
+            .iter()
+            .filter(|(path, profile)| {
+                included.contains(*profile) && !config.is_system_value(&["path_mappings", path])
+            })
+            .map(|(path, profile)| (path.clone(), profile.clone()))
+            .collect();
+
+        let encryption = match key {
+            Some((key, salt)) => Some(BundleEncryption {
+                kdf: "pbkdf2-sha256".to_string(),
+                iterations: PASSPHRASE_ITERATIONS,
+                salt: general_purpose::STANDARD.encode(salt),
+                check: TokenCrypto::encrypt_with_passphrase(PASSPHRASE_CHECK, key)?,
+            }),
+            None => None,
+        };
+
+        Ok(Bundle {
+            format: BUNDLE_FORMAT.to_string(),
+            version: BUNDLE_VERSION,
+            encryption,
+            profiles,
+            path_mappings,
+        })
+    }
+
+    /// 번들 파일 읽기 (형식과 버전 확인)
+    pub fn load(path: &Path) -> Result<Self> {
+        let display = path.display().to_string();
+        let content = fs::read_to_string(path)?;
+        let table: toml::Table =
+            toml::from_str(&content).map_err(|err| Error::LayerParse(display.clone(), err))?;
+        if table.get("format").and_then(|format| format.as_str()) != Some(BUNDLE_FORMAT) {
+            return Err(Error::InvalidBundle(display, t!("bundle.not_bundle")));
+        }
+        let bundle: Bundle = table
+            .try_into()
+            .map_err(|err| Error::LayerParse(display.clone(), err))?;
+
+        if bundle.version > BUNDLE_VERSION {
+            return Err(Error::InvalidBundle(
+                display,
+                t!("bundle.newer_version", bundle.version, BUNDLE_VERSION),
+            ));
+        }
+        if let Some(encryption) = &bundle.encryption
+            && encryption.kdf != "pbkdf2-sha256"
+        {
+            return Err(Error::InvalidBundle(
+                display,
+                t!("bundle.unknown_kdf", encryption.kdf),
+            ));
+        }
+        Ok(bundle)
+    }
+
+    /// 번들 파일로 저장 (PAT가 없어도 신원 정보이므로 권한 0600)
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = format!(
+            "# {}\n{}",
+            t!("bundle.header"),
+            toml::to_string_pretty(self)?
+        );
+        write_atomic(path, content.as_bytes())
+    }
+
+    /// 암호화된 PAT가 든 프로필 수
+    pub fn token_count(&self) -> usize {
+        self.profiles
+            .values()
+            .filter(|profile| profile.encrypted_pat.is_some())
+            .count()
+    }
+
+    /// 번들 암호로 키를 유도하고 맞는 암호인지 확인
+    pub fn unlock(&self, passphrase: &str) -> Result<PassphraseKey> {
+        let Some(encryption) = &self.encryption else {
+            return Err(Error::WrongPassphrase);
+        };
+        let salt = general_purpose::STANDARD
+            .decode(&encryption.salt)
+            .map_err(|e| Error::Decryption(t!("crypto.decode_failed", e)))?;
+        let key = PassphraseKey::derive(passphrase, &salt, encryption.iterations);
+        TokenCrypto::decrypt_with_passphrase(&encryption.check, &key)?;
+        Ok(key)
+    }
+
+    /// 번들을 설정에 병합
+    ///
+    /// `key`가 없으면 PAT는 가져오지 않고, 있으면 이 빌드의 키로 다시 암호화해서 저장
+    pub fn merge_into(
+        self,
+        config: &mut Config,
+        on_conflict: OnConflict,
+        key: Option<&PassphraseKey>,
+    ) -> Result<MergeReport> {
+        let mut report = MergeReport {
+            profiles: Vec::new(),
+            path_mappings: Vec::new(),
+            tokens: 0,
+            tokens_skipped: 0,
+        };
+
+        let mut profiles = self.profiles;
+        let mut with_token = BTreeSet::new();
+        for (name, profile) in profiles.iter_mut() {
+            let Some(encrypted) = profile.encrypted_pat.take() else {
+                continue;
+            };
+            with_token.insert(name.clone());
+            if let Some(key) = key {
+                let pat = TokenCrypto::decrypt_with_passphrase(&encrypted, key)?;
+                profile.set_encrypted_pat(&pat)?;
+            }
+        }
+
+        // 먼저 모든 프로필의 저장 이름을 정해야 extends를 바꿔 쓸 수 있음
+        let mut targets: BTreeMap<String, (String, MergeOutcome)> = BTreeMap::new();
+        for (name, profile) in &profiles {
+            let target = match config.profiles.get(name) {
+                None => (name.clone(), MergeOutcome::Added),
+                Some(existing) if same_profile(existing, profile)? => {
+                    (name.clone(), MergeOutcome::Unchanged)
+                }
+                Some(_) => match on_conflict {
+                    OnConflict::Skip => (name.clone(), MergeOutcome::Skipped),
+                    OnConflict::Overwrite => (name.clone(), MergeOutcome::Overwritten),
+                    OnConflict::Rename => {
+                        let renamed = renamed_profile(name, config, &profiles, &targets);
+                        (renamed, MergeOutcome::Renamed)
+                    }
+                },
+            };
+            targets.insert(name.clone(), target);
+        }
+
+        for (name, mut profile) in profiles {
+            let (target, outcome) = targets[&name].clone();
+            if let Some(parent) = &profile.extends
+                && let Some((renamed, MergeOutcome::Renamed)) = targets.get(parent)
+            {
+                profile.extends = Some(renamed.clone());
+            }
+            if matches!(
+                outcome,
+                MergeOutcome::Added | MergeOutcome::Overwritten | MergeOutcome::Renamed
+            ) {
+                if with_token.contains(&name) {
+                    if profile.encrypted_pat.is_some() {
+                        report.tokens += 1;
+                    } else {
+                        report.tokens_skipped += 1;
+                    }
+                }
+                // 덮어쓸 때 번들에 PAT가 없으면 기존 PAT 유지
+                if profile.encrypted_pat.is_none()
+                    && let Some(existing) = config.profiles.get(&target)
+                {
+                    profile.encrypted_pat = existing.encrypted_pat.clone();
+                }
+                config.add_profile(target.clone(), profile);
+            }
+            report.profiles.push(MergedEntry {
+                name,
+                target,
+                outcome,
+            });
+        }
+
+        for (path, profile) in self.path_mappings {
+            let profile = targets
+                .get(&profile)
+                .map(|(target, _)| target.clone())
+                .unwrap_or(profile);
+            let outcome = match config.path_mappings.get(&path) {
+                None => MergeOutcome::Added,
+                Some(existing) if *existing == profile => MergeOutcome::Unchanged,
+                Some(_) if on_conflict == OnConflict::Overwrite => MergeOutcome::Overwritten,
+                Some(_) => MergeOutcome::Skipped,
+            };
+            if matches!(outcome, MergeOutcome::Added | MergeOutcome::Overwritten) {
+                config.add_path_mapping(path.clone(), profile.clone());
+            }
+            report.path_mappings.push(MergedEntry {
+                name: path,
+                target: profile,
+                outcome,
+            });
+        }
+
+        Ok(report)
+    }
+}
+
+/// PAT는 암호문이 매번 달라지므로 복호화한 값으로 비교
+fn same_profile(existing: &Profile, imported: &Profile) -> Result<bool> {
+    let without_token = |profile: &Profile| {
+        let mut profile = profile.clone();
+        profile.encrypted_pat = None;
+        toml::Value::try_from(profile)
+    };
+    if without_token(existing)? != without_token(imported)? {
+        return Ok(false);
+    }
+    Ok(imported.encrypted_pat.is_none()
+        || existing.get_decrypted_pat().ok().flatten() == imported.get_decrypted_pat()?)
+}
+
+/// 설정과 번들 어디에도 없는 `<이름>-imported`, `<이름>-imported-2`, ...
+fn renamed_profile(
+    name: &str,
+    config: &Config,
+    profiles: &BTreeMap<String, Profile>,
+    targets: &BTreeMap<String, (String, MergeOutcome)>,
+) -> String {
+    let taken = |candidate: &str| {
+        config.profiles.contains_key(candidate)
+            || profiles.contains_key(candidate)
+            || targets.values().any(|(target, _)| target == candidate)
+    };
+    let base = format!("{}-imported", name);
+    if !taken(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{}-{}", base, n))
+        .find(|candidate| !taken(candidate))
+        .unwrap_or_default()
+}
@@ -1,19 +1,60 @@
-use crate::core::{Error, Result};
+use crate::core::layers::{self, ConfigLayer, KeyPath, LayerInfo, RepoLayer, Setting, SystemLayer};
+use crate::core::migration::{self, CONFIG_VERSION};
+use crate::core::{Error, Result, document, policy};
+use crate::git::GitConfig;
+use crate::{report, t};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use toml_edit::DocumentMut;
 
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize)]
 pub struct Config {
-    pub profiles: HashMap<String, Profile>,
+    /// 설정 파일 스키마 버전 (오래된 파일은 읽을 때 현재 버전으로 변환)
     #[serde(default)]
-    pub path_mappings: HashMap<String, String>,
+    pub version: u32,
+    /// 메시지 표시 언어 (`ko`, `en`; 없으면 로캘 환경 변수로 결정)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// 원격 저장소별 신원 규칙을 담은 팀 정책 파일 경로
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<String>,
+    /// 모든 프로필에 공통으로 적용되는 기본값 (`[defaults]`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub defaults: Option<Profile>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+    #[serde(default)]
+    pub path_mappings: BTreeMap<String, String>,
+    /// 원격 주소 패턴(`host/경로` glob)별 프로필 (`clone`에서 경로 매핑보다 먼저 확인)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub remote_mappings: BTreeMap<String, String>,
+    /// 말단 키별로 값이 정의된 레이어
+    #[serde(skip)]
+    origins: BTreeMap<KeyPath, ConfigLayer>,
+    /// 병합된 시스템 레이어 (저장할 때 물려받은 값을 빼는 데 사용)
+    #[serde(skip)]
+    system: Option<SystemLayer>,
+    /// 현재 저장소의 `.git-switcher.toml`
+    #[serde(skip)]
+    repo: Option<RepoLayer>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct Profile {
+    /// 값을 상속받을 부모 프로필 이름
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+    /// 비어 있으면 부모 프로필이나 `[defaults]`에서 상속
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub email: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signingkey: Option<String>,
@@ -24,6 +65,84 @@ pub struct Profile {
     /// 암호화된 GitHub Personal Access Token
     #[serde(skip_serializing_if = "Option::is_none")]
     pub encrypted_pat: Option<String>,
+    /// 프로필 적용 시 로컬 저장소에 설정할 추가 Git 설정
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub git_config: BTreeMap<String, GitConfigValue>,
+}
+
+/// `[profiles.x.git_config]` 테이블의 값
+///
+/// 점이 포함된 키를 따옴표 없이 쓰면 TOML에서 중첩 테이블이 되므로
+/// (`url."https://github.com/".insteadOf`), 중첩 테이블은 점으로 이어 붙여 하나의 키로 취급한다.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum GitConfigValue {
+    Bool(bool),
+    Integer(i64),
+    String(String),
+    /// 다중 값 키 (`git config --add`로 순서대로 추가)
+    Multiple(Vec<GitConfigScalar>),
+    Table(BTreeMap<String, GitConfigValue>),
+}
+
+/// 다중 값 키의 값 하나 (단일 값과 같이 bool, 정수, 문자열)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum GitConfigScalar {
+    Bool(bool),
+    Integer(i64),
+    String(String),
+}
+
+impl fmt::Display for GitConfigScalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GitConfigScalar::Bool(value) => write!(f, "{}", value),
+            GitConfigScalar::Integer(value) => write!(f, "{}", value),
+            GitConfigScalar::String(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl GitConfigValue {
+    fn flatten_into(&self, key: String, entries: &mut Vec<(String, Vec<String>)>) {
+        match self {
+            GitConfigValue::Bool(value) => entries.push((key, vec![value.to_string()])),
+            GitConfigValue::Integer(value) => entries.push((key, vec![value.to_string()])),
+            GitConfigValue::String(value) => entries.push((key, vec![value.clone()])),
+            GitConfigValue::Multiple(values) => {
+                entries.push((key, values.iter().map(ToString::to_string).collect()))
+            }
+            GitConfigValue::Table(table) => {
+                for (sub_key, value) in table {
+                    value.flatten_into(format!("{}.{}", key, sub_key), entries);
+                }
+            }
+        }
+    }
+}
+
+/// 병합된 프로필 값의 출처 계층
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProfileSource {
+    Defaults,
+    Profile(String),
+}
+
+impl fmt::Display for ProfileSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileSource::Defaults => write!(f, "[defaults]"),
+            ProfileSource::Profile(name) => write!(f, "[profiles.{}]", name),
+        }
+    }
+}
+
+/// `extends`와 `[defaults]`를 모두 반영한 최종 프로필
+pub struct ResolvedProfile {
+    pub profile: Profile,
+    /// 필드별 출처 (`git_config.<키>` 형태의 키 포함)
+    pub origins: HashMap<String, ProfileSource>,
 }
 
 impl Profile {
@@ -49,6 +168,108 @@ impl Profile {
         Ok(())
     }
 
+    /// 추가 Git 설정을 `(키, 값 목록)` 형태로 펼쳐서 반환
+    pub fn git_config_entries(&self) -> Vec<(String, Vec<String>)> {
+        let mut entries = Vec::new();
+        for (key, value) in &self.git_config {
+            value.flatten_into(key.clone(), &mut entries);
+        }
+        entries
+    }
+
+    /// 목록 표시용 한 줄 요약 (`이름 <이메일> [호스트] [SSH] [PAT]`)
+    pub fn summary(&self) -> String {
+        let mut summary = format!("{} <{}>", self.name, self.email);
+        if let Some(github_username) = &self.github_username {
+            summary.push_str(&format!(" [github.com: {}]", github_username));
+        }
+        if self.ssh_key.is_some() {
+            summary.push_str(" [SSH]");
+        }
+        if let Some(masked) = self.get_masked_pat() {
+            summary.push_str(&format!(" [PAT: {}]", masked));
+        }
+        summary
+    }
+
+    /// 설정된 필드를 `(키, 표시용 값)` 목록으로 반환 (PAT는 마스킹)
+    pub fn display_fields(&self) -> Vec<(String, String)> {
+        let mut fields = Vec::new();
+
+        if let Some(extends) = &self.extends {
+            fields.push(("extends".to_string(), extends.clone()));
+        }
+        if !self.name.is_empty() {
+            fields.push(("name".to_string(), self.name.clone()));
+        }
+        if !self.email.is_empty() {
+            fields.push(("email".to_string(), self.email.clone()));
+        }
+        for (field, value) in [
+            ("signingkey", &self.signingkey),
+            ("ssh_key", &self.ssh_key),
+            ("github_username", &self.github_username),
+        ] {
+            if let Some(value) = value {
+                fields.push((field.to_string(), value.clone()));
+            }
+        }
+        if self.has_pat() {
+            let masked = self
+                .get_masked_pat()
+                .unwrap_or_else(|| t!("profile.decrypt_failed"));
+            fields.push(("encrypted_pat".to_string(), masked));
+        }
+        for (key, values) in self.git_config_entries() {
+            fields.push((format!("git_config.{}", key), values.join(", ")));
+        }
+
+        fields
+    }
+
+    /// 다른 계층에서 설정된 값만 덮어쓰고 출처를 기록
+    fn merge_from(
+        &mut self,
+        layer: &Profile,
+        source: &ProfileSource,
+        origins: &mut HashMap<String, ProfileSource>,
+    ) {
+        if !layer.name.is_empty() {
+            self.name = layer.name.clone();
+            origins.insert("name".to_string(), source.clone());
+        }
+        if !layer.email.is_empty() {
+            self.email = layer.email.clone();
+            origins.insert("email".to_string(), source.clone());
+        }
+        for (field, target, value) in [
+            ("signingkey", &mut self.signingkey, &layer.signingkey),
+            ("ssh_key", &mut self.ssh_key, &layer.ssh_key),
+            (
+                "github_username",
+                &mut self.github_username,
+                &layer.github_username,
+            ),
+            (
+                "encrypted_pat",
+                &mut self.encrypted_pat,
+                &layer.encrypted_pat,
+            ),
+        ] {
+            if value.is_some() {
+                *target = value.clone();
+                origins.insert(field.to_string(), source.clone());
+            }
+        }
+        // 중첩 테이블도 키 단위로 병합되도록 펼친 키 기준으로 덮어씀
+        for (key, values) in layer.git_config_entries() {
+            origins.insert(format!("git_config.{}", key), source.clone());
+            let values = values.into_iter().map(GitConfigScalar::String).collect();
+            self.git_config
+                .insert(key, GitConfigValue::Multiple(values));
+        }
+    }
+
     /// 안전하게 마스킹된 PAT 정보 표시
     pub fn get_masked_pat(&self) -> Option<String> {
         if let Ok(Some(pat)) = self.get_decrypted_pat() {
@@ -59,29 +280,322 @@ impl Profile {
     }
 }
 
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            version: CONFIG_VERSION,
+            language: None,
+            policy: None,
+            defaults: None,
+            profiles: BTreeMap::new(),
+            path_mappings: BTreeMap::new(),
+            remote_mappings: BTreeMap::new(),
+            origins: BTreeMap::new(),
+            system: None,
+            repo: None,
+        }
+    }
+}
+
+/// 설정 파일 잠금 (drop되면 해제)
+///
+/// 이미 잠금을 가진 프로세스가 다시 잠그면 파일 없이 만들어져 아무것도 하지 않음
+pub struct ConfigLock {
+    file: Option<fs::File>,
+}
+
+impl Drop for ConfigLock {
+    fn drop(&mut self) {
+        if self.file.take().is_some() {
+            LOCK_HELD.store(false, Ordering::SeqCst);
+        }
+    }
+}
+
+static LOCK_HELD: AtomicBool = AtomicBool::new(false);
+
+/// 오래된 설정 파일을 변환한 결과
+pub struct Migration {
+    pub from: u32,
+    /// 변환 전 원본을 복사해 둔 경로
+    pub backup: PathBuf,
+}
+
 impl Config {
+    /// 시스템, 사용자, 저장소 레이어를 읽어 병합
+    ///
+    /// 사용자 설정이 오래된 스키마 버전이면 원본을 백업한 뒤 현재 버전으로 변환해 저장.
+    /// 사용자 설정이 없어도 시스템 레이어가 있으면 그것만으로 사용
     pub fn load() -> Result<Self> {
         let config_path = get_config_path()?;
 
+        let user = if config_path.exists() {
+            warn_if_readable_by_others(&config_path)?;
+            let mut content = fs::read_to_string(&config_path)?;
+            if migration::document_version(&parse_document(&content)?)? < CONFIG_VERSION {
+                Self::migrate()?;
+                content = fs::read_to_string(&config_path)?;
+            }
+            // 스키마 오류도 줄/열 위치와 함께 보고되도록 구조체로 먼저 해석
+            toml::from_str::<Config>(&content)?;
+            toml::from_str::<toml::Table>(&content)?
+        } else if layers::system_config_path().exists() {
+            toml::Table::new()
+        } else {
+            return Err(Error::ConfigNotFound);
+        };
+
+        Self::from_layers(user)
+    }
+
+    /// 사용자 레이어 아래에 시스템 레이어를 채우고 저장소 레이어를 붙임
+    fn from_layers(mut table: toml::Table) -> Result<Self> {
+        let mut origins = BTreeMap::new();
+        for (key, value) in &table {
+            layers::record_leaves(
+                value,
+                &mut vec![key.clone()],
+                ConfigLayer::User,
+                &mut origins,
+            );
+        }
+
+        let system = layers::load_system()?;
+        if let Some(system) = &system {
+            layers::merge_missing(
+                &mut table,
+                &system.table,
+                &mut Vec::new(),
+                ConfigLayer::System,
+                &mut origins,
+            );
+        }
+
+        let mut config: Config = toml::Value::Table(table).try_into()?;
+        config.version = CONFIG_VERSION;
+        config.origins = origins;
+        config.system = system;
+        config.repo = layers::load_repo()?;
+        Ok(config)
+    }
+
+    /// 현재 디렉토리 기준으로 저장소 레이어를 다시 읽음 (여러 저장소를 돌며 적용할 때)
+    pub fn reload_repo_layer(&mut self) -> Result<()> {
+        self.repo = layers::load_repo()?;
+        Ok(())
+    }
+
+    /// 현재 저장소의 `.git-switcher.toml`
+    pub fn repo_layer(&self) -> Option<&RepoLayer> {
+        self.repo.as_ref()
+    }
+
+    /// 키(또는 그 아래 키 중 하나)가 시스템 레이어에서 왔는지
+    pub fn is_system_value(&self, path: &[&str]) -> bool {
+        self.origins.iter().any(|(key, layer)| {
+            *layer == ConfigLayer::System
+                && key.len() >= path.len()
+                && key.iter().zip(path).all(|(a, b)| a == b)
+        })
+    }
+
+    /// 시스템 레이어의 값은 사용자 설정에서 지우거나 옮길 수 없으므로 거부
+    pub fn ensure_user_value(&self, path: &[&str]) -> Result<()> {
+        if self.is_system_value(path) {
+            let path: Vec<String> = path.iter().map(|key| key.to_string()).collect();
+            return Err(Error::SystemManaged(layers::display_key(&path)));
+        }
+        Ok(())
+    }
+
+    /// 저장소 레이어의 제약(사용할 프로필, 허용 이메일 도메인) 확인
+    pub fn check_repo_policy(&self, profile_name: &str, profile: &Profile) -> Result<()> {
+        let Some(repo) = &self.repo else {
+            return Ok(());
+        };
+
+        if let Some(required) = &repo.policy.profile
+            && required != profile_name
+        {
+            return Err(Error::PolicyViolation(t!(
+                "policy.required_profile",
+                repo.path,
+                required,
+                profile_name
+            )));
+        }
+        if !repo.policy.allows_email(&profile.email) {
+            return Err(Error::PolicyViolation(t!(
+                "policy.email_domain",
+                profile.email,
+                repo.path,
+                repo.policy.allowed_email_domains.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    /// 각 레이어 파일의 경로와 사용 여부
+    pub fn layer_infos(&self) -> Result<Vec<LayerInfo>> {
+        let user_path = get_config_path()?;
+        let repo_path = match &self.repo {
+            Some(repo) => Some(repo.path.clone()),
+            None => GitConfig::get_repo_root().ok().map(|root| {
+                Path::new(&root)
+                    .join(layers::REPO_CONFIG_FILE)
+                    .display()
+                    .to_string()
+            }),
+        };
+
+        let mut infos = vec![
+            LayerInfo {
+                layer: ConfigLayer::System,
+                path: Some(layers::system_config_path().display().to_string()),
+                loaded: self.system.is_some(),
+            },
+            LayerInfo {
+                layer: ConfigLayer::User,
+                loaded: user_path.exists(),
+                path: Some(user_path.display().to_string()),
+            },
+        ];
+        infos.push(LayerInfo {
+            layer: ConfigLayer::Repo,
+            path: repo_path,
+            loaded: self.repo.is_some(),
+        });
+        Ok(infos)
+    }
+
+    /// 병합된 모든 설정 값과 그 값이 정의된 레이어 (PAT는 가림)
+    pub fn settings(&self) -> Result<Vec<Setting>> {
+        let merged = toml::Table::try_from(self)?;
+        let mut settings: Vec<Setting> = self
+            .origins
+            .iter()
+            .filter_map(|(path, layer)| {
+                let value = layers::lookup(&merged, path)?;
+                Some(Setting::new(path, value.clone(), *layer))
+            })
+            .collect();
+
+        if let Some(repo) = &self.repo {
+            for (key, value) in toml::Table::try_from(&repo.policy)? {
+                settings.push(Setting::new(&[key], value, ConfigLayer::Repo));
+            }
+        }
+        Ok(settings)
+    }
+
+    /// 설정 파일의 스키마 버전 (변환하지 않고 확인만)
+    pub fn file_version() -> Result<u32> {
+        let config_path = get_config_path()?;
+        if !config_path.exists() {
+            return Err(Error::ConfigNotFound);
+        }
+        migration::document_version(&parse_document(&fs::read_to_string(&config_path)?)?)
+    }
+
+    /// 오래된 설정 파일을 현재 버전으로 변환 (이미 최신이면 `None`)
+    pub fn migrate() -> Result<Option<Migration>> {
+        let _lock = Self::lock()?;
+        let config_path = get_config_path()?;
         if !config_path.exists() {
             return Err(Error::ConfigNotFound);
         }
 
+        // 잠금을 기다리는 동안 다른 프로세스가 이미 변환했을 수 있으므로 다시 읽음
         let content = fs::read_to_string(&config_path)?;
-        let config: Config = toml::from_str(&content)?;
-        Ok(config)
+        let mut document = parse_document(&content)?;
+        let from = migration::document_version(&document)?;
+        if from == CONFIG_VERSION {
+            return Ok(None);
+        }
+
+        migration::migrate(&mut document, from);
+        let migrated = document.to_string();
+        // 변환 결과가 현재 스키마로 읽히는지 확인한 뒤에 백업하고 덮어씀
+        toml::from_str::<Config>(&migrated)?;
+        let backup = write_backup(&config_path, from, content.as_bytes())?;
+        write_atomic(&config_path, migrated.as_bytes())?;
+
+        report!(
+            Success,
+            "{}",
+            t!("config.migrated", from, CONFIG_VERSION, backup.display())
+        );
+        Ok(Some(Migration { from, backup }))
+    }
+
+    /// 설정 파일의 `language` 값 (스키마 변환 없이 읽음)
+    ///
+    /// 리포터를 등록하기 전, 언어를 정할 때 사용
+    pub fn language_setting() -> Option<String> {
+        let read = |path: PathBuf| {
+            let document = fs::read_to_string(path).ok()?.parse::<DocumentMut>().ok()?;
+            document.get("language")?.as_str().map(str::to_string)
+        };
+        read(get_config_path().ok()?).or_else(|| read(layers::system_config_path()))
     }
 
+    /// 설정 파일이 아직 없을 때만 빈 설정을 반환 (파싱 오류 등은 그대로 전달)
+    pub fn load_or_default() -> Result<Self> {
+        match Self::load() {
+            Err(Error::ConfigNotFound) => Ok(Config::default()),
+            result => result,
+        }
+    }
+
+    /// 임시 파일에 쓰고 fsync한 뒤 rename해서, 중간에 중단되어도 기존 파일이 깨지지 않게 저장
+    ///
+    /// 기존 파일이 있으면 바뀐 부분만 고쳐 써서 주석과 순서를 유지.
+    /// 시스템 레이어에서 물려받아 바뀌지 않은 값은 사용자 설정에 쓰지 않음
     pub fn save(&self) -> Result<()> {
         let config_path = get_config_path()?;
+        let mut content = match &self.system {
+            Some(system) => {
+                let mut table = toml::Table::try_from(self)?;
+                layers::strip_inherited(
+                    &mut table,
+                    &system.table,
+                    &self.origins,
+                    ConfigLayer::System,
+                );
+                toml::to_string_pretty(&table)?
+            }
+            None => toml::to_string_pretty(self)?,
+        };
+        if let Ok(existing) = fs::read_to_string(&config_path) {
+            content = document::merge_preserving_format(&existing, &content);
+        }
+        write_atomic(&config_path, content.as_bytes())
+    }
+
+    /// 읽고 고쳐 쓰는 동안 다른 git-switcher 프로세스가 끼어들지 않도록 잠금
+    ///
+    /// 다른 프로세스가 잠금을 가지고 있으면 풀릴 때까지 대기
+    pub fn lock() -> Result<ConfigLock> {
+        if LOCK_HELD.load(Ordering::SeqCst) {
+            return Ok(ConfigLock { file: None });
+        }
 
+        let config_path = get_config_path()?;
         if let Some(parent) = config_path.parent() {
-            fs::create_dir_all(parent)?;
+            create_private_dir(parent)?;
         }
 
-        let content = toml::to_string_pretty(self)?;
-        fs::write(&config_path, content)?;
-        Ok(())
+        let mut options = fs::OpenOptions::new();
+        options.create(true).truncate(false).write(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let file = options.open(with_suffix(&config_path, ".lock"))?;
+        file.lock()?;
+        LOCK_HELD.store(true, Ordering::SeqCst);
+        Ok(ConfigLock { file: Some(file) })
     }
 
     pub fn get_profile(&self, name: &str) -> Result<&Profile> {
@@ -90,15 +604,133 @@ impl Config {
             .ok_or_else(|| Error::ProfileNotFound(name.to_string()))
     }
 
+    /// `[defaults]`와 `extends` 체인을 병합한 최종 프로필 반환
+    pub fn resolve_profile(&self, name: &str) -> Result<Profile> {
+        self.resolve_profile_with_origins(name)
+            .map(|resolved| resolved.profile)
+    }
+
+    /// 최종 프로필과 함께 각 필드가 어느 계층에서 왔는지 반환
+    pub fn resolve_profile_with_origins(&self, name: &str) -> Result<ResolvedProfile> {
+        // 자식 -> 부모 순서로 상속 체인 수집
+        let mut chain = vec![(name.to_string(), self.get_profile(name)?)];
+        while let Some(parent) = &chain[chain.len() - 1].1.extends {
+            if chain.iter().any(|(visited, _)| visited == parent) {
+                let mut names: Vec<&str> = chain.iter().map(|(n, _)| n.as_str()).collect();
+                names.push(parent);
+                return Err(Error::InheritanceCycle(names.join(" -> ")));
+            }
+            chain.push((parent.clone(), self.get_profile(parent)?));
+        }
+
+        let mut profile = Profile {
+            extends: None,
+            name: String::new(),
+            email: String::new(),
+            signingkey: None,
+            ssh_key: None,
+            github_username: None,
+            encrypted_pat: None,
+            git_config: BTreeMap::new(),
+        };
+        let mut origins = HashMap::new();
+
+        if let Some(defaults) = &self.defaults {
+            profile.merge_from(defaults, &ProfileSource::Defaults, &mut origins);
+        }
+        for (layer_name, layer) in chain.iter().rev() {
+            let source = ProfileSource::Profile(layer_name.clone());
+            profile.merge_from(layer, &source, &mut origins);
+        }
+
+        if profile.name.is_empty() {
+            return Err(Error::IncompleteProfile(name.to_string(), "name"));
+        }
+        if profile.email.is_empty() {
+            return Err(Error::IncompleteProfile(name.to_string(), "email"));
+        }
+
+        Ok(ResolvedProfile { profile, origins })
+    }
+
+    pub fn get_profile_mut(&mut self, name: &str) -> Result<&mut Profile> {
+        self.profiles
+            .get_mut(name)
+            .ok_or_else(|| Error::ProfileNotFound(name.to_string()))
+    }
+
+    /// 프로필 이름을 바꾸고 이를 참조하는 경로/원격 매핑과 `extends`도 함께 변경
+    ///
+    /// 변경된 매핑의 경로(원격 매핑은 패턴) 목록을 반환
+    pub fn rename_profile(&mut self, old_name: &str, new_name: &str) -> Result<Vec<String>> {
+        self.ensure_user_value(&["profiles", old_name])?;
+        if self.profiles.contains_key(new_name) {
+            return Err(Error::ProfileExists(new_name.to_string()));
+        }
+        let profile = self
+            .profiles
+            .remove(old_name)
+            .ok_or_else(|| Error::ProfileNotFound(old_name.to_string()))?;
+        self.profiles.insert(new_name.to_string(), profile);
+
+        for profile in self.profiles.values_mut() {
+            if profile.extends.as_deref() == Some(old_name) {
+                profile.extends = Some(new_name.to_string());
+            }
+        }
+
+        let mut updated_paths = Vec::new();
+        let mappings = self
+            .path_mappings
+            .iter_mut()
+            .chain(self.remote_mappings.iter_mut());
+        for (path, profile_name) in mappings {
+            if profile_name == old_name {
+                *profile_name = new_name.to_string();
+                updated_paths.push(path.clone());
+            }
+        }
+
+        Ok(updated_paths)
+    }
+
     pub fn add_profile(&mut self, name: String, profile: Profile) {
         self.profiles.insert(name, profile);
     }
 
-    pub fn remove_profile(&mut self, name: &str) -> Result<()> {
-        if self.profiles.remove(name).is_none() {
-            return Err(Error::ProfileNotFound(name.to_string()));
+    /// 프로필 제거
+    ///
+    /// 경로/원격 매핑이 가리키고 있으면 `cascade`일 때 매핑도 함께 제거하고(제거한 경로와 패턴 반환),
+    /// 아니면 거부. 다른 프로필이 상속하고 있으면 항상 거부
+    pub fn remove_profile(&mut self, name: &str, cascade: bool) -> Result<Vec<String>> {
+        self.get_profile(name)?;
+        self.ensure_user_value(&["profiles", name])?;
+
+        let mappings: Vec<String> = self
+            .path_mappings
+            .iter()
+            .chain(&self.remote_mappings)
+            .filter(|(_, profile)| *profile == name)
+            .map(|(path, _)| path.clone())
+            .collect();
+        let children: Vec<String> = self
+            .profiles
+            .iter()
+            .filter(|(_, profile)| profile.extends.as_deref() == Some(name))
+            .map(|(child, _)| child.clone())
+            .collect();
+        if !children.is_empty() || (!cascade && !mappings.is_empty()) {
+            return Err(Error::ProfileInUse {
+                name: name.to_string(),
+                mappings,
+                children,
+            });
         }
-        Ok(())
+
+        self.profiles.remove(name);
+        self.path_mappings.retain(|_, profile| profile != name);
+        self.remote_mappings.retain(|_, profile| profile != name);
+        Ok(mappings)
     }
 
     pub fn add_path_mapping(&mut self, path: String, profile: String) {
@@ -109,73 +741,350 @@ impl Config {
         self.path_mappings.remove(path).is_some()
     }
 
+    pub fn add_remote_mapping(&mut self, pattern: String, profile: String) {
+        self.remote_mappings.insert(pattern, profile);
+    }
+
+    pub fn remove_remote_mapping(&mut self, pattern: &str) -> bool {
+        self.remote_mappings.remove(pattern).is_some()
+    }
+
+    /// 원격 주소에 일치하는 `(패턴, 프로필 이름)` (여러 개면 가장 긴 패턴)
+    pub fn find_mapping_for_remote(&self, url: &str) -> Option<(&String, &String)> {
+        let normalized = policy::normalize_remote(url)?;
+        self.remote_mappings
+            .iter()
+            .filter(|(pattern, _)| policy::glob_match(&pattern.to_lowercase(), &normalized))
+            .max_by_key(|(pattern, _)| pattern.len())
+    }
+
     pub fn find_profile_for_path(&self, current_path: &str) -> Option<&String> {
+        self.find_mapping_for_path(current_path)
+            .map(|(_, profile)| profile)
+    }
+
+    /// 경로에 가장 길게 일치하는 `(매핑 경로, 프로필 이름)`
+    ///
+    /// 경로 구성 요소 단위로 비교하므로 `~/work`는 `~/workspace`와 일치하지 않음 (git의 `includeIf "gitdir:~/work/"`와 같음)
+    ///
+    /// 저장소의 `.git-switcher.toml`이 프로필을 지정하면 그 저장소 안에서는
+    /// `(.git-switcher.toml 경로, 프로필 이름)`을 우선 반환
+    pub fn find_mapping_for_path(&self, current_path: &str) -> Option<(&String, &String)> {
+        if let Some(repo) = &self.repo
+            && let Some(profile) = &repo.policy.profile
+            && Path::new(current_path).starts_with(&repo.root)
+        {
+            return Some((&repo.path, profile));
+        }
+
         let mut best_match: Option<(&String, &String)> = None;
         let mut best_length = 0;
 
         for (mapped_path, profile) in &self.path_mappings {
             let expanded_path = expand_path(mapped_path);
-            if current_path.starts_with(&expanded_path) && expanded_path.len() > best_length {
+            if Path::new(current_path).starts_with(&expanded_path)
+                && expanded_path.len() > best_length
+            {
                 best_match = Some((mapped_path, profile));
                 best_length = expanded_path.len();
             }
         }
 
-        best_match.map(|(_, profile)| profile)
+        best_match
+    }
+
+    /// 이름과 이메일이 일치하는 프로필 이름 (상속 병합 결과 기준)
+    pub fn find_profile_by_identity(&self, name: &str, email: &str) -> Option<&String> {
+        self.profiles.keys().find(|profile_name| {
+            self.resolve_profile(profile_name)
+                .map(|profile| profile.name == name && profile.email == email)
+                .unwrap_or(false)
+        })
     }
 
     pub fn init_default() -> Result<Self> {
-        let mut profiles = HashMap::new();
+        let mut profiles = BTreeMap::new();
 
         profiles.insert(
             "personal".to_string(),
             Profile {
+                extends: None,
                 name: "Your Name".to_string(),
                 email: "your.email@personal.com".to_string(),
                 signingkey: None,
                 ssh_key: None,
                 github_username: Some("your-github-username".to_string()),
                 encrypted_pat: None,
+                git_config: BTreeMap::new(),
             },
         );
 
         profiles.insert(
             "company".to_string(),
             Profile {
+                extends: None,
                 name: "Your Name".to_string(),
                 email: "your.email@company.com".to_string(),
                 signingkey: None,
                 ssh_key: None,
                 github_username: Some("your-work-username".to_string()),
                 encrypted_pat: None,
+                git_config: BTreeMap::new(),
             },
         );
 
-        let mut path_mappings = HashMap::new();
+        let mut path_mappings = BTreeMap::new();
         path_mappings.insert("~/workspace/personal/".to_string(), "personal".to_string());
         path_mappings.insert("~/workspace/company/".to_string(), "company".to_string());
 
         let config = Config {
             profiles,
             path_mappings,
+            ..Config::default()
         };
         config.save()?;
         Ok(config)
     }
 }
 
+static CONFIG_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// 프로세스 전체에서 사용할 설정 파일 경로 지정 (`--config`)
+pub fn set_config_path(path: PathBuf) {
+    if let Ok(mut current) = CONFIG_PATH.write() {
+        *current = Some(path);
+    }
+}
+
+/// 설정 파일 경로
+///
+/// `set_config_path`로 지정한 경로 > `GIT_SWITCHER_CONFIG` >
+/// `$XDG_CONFIG_HOME/git-switcher/config.toml` > `~/.config/git-switcher/config.toml` 순서로 결정.
+/// 잠금, 백업, 임시 파일도 모두 이 경로 옆에 만듦
 pub fn get_config_path() -> Result<PathBuf> {
-    let home_dir = dirs::home_dir()
-        .ok_or_else(|| Error::Other("홈 디렉토리를 찾을 수 없습니다".to_string()))?;
-    let config_dir = home_dir.join(".config").join("git-switcher");
-    Ok(config_dir.join("config.toml"))
+    if let Ok(current) = CONFIG_PATH.read()
+        && let Some(path) = current.as_ref()
+    {
+        return Ok(path.clone());
+    }
+
+    if let Some(path) = env::var_os("GIT_SWITCHER_CONFIG").filter(|path| !path.is_empty()) {
+        return Ok(PathBuf::from(path));
+    }
+
+    // XDG 명세에 따라 상대 경로인 XDG_CONFIG_HOME은 무시
+    let config_home = match env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+    {
+        Some(path) => path,
+        None => dirs::home_dir().ok_or(Error::HomeNotFound)?.join(".config"),
+    };
+    Ok(config_home.join("git-switcher").join("config.toml"))
 }
 
-fn expand_path(path: &str) -> String {
-    if path.starts_with("~/") {
-        if let Some(home) = dirs::home_dir() {
-            return path.replace("~", &home.to_string_lossy());
+/// 설정 파일 이름 뒤에 접미사를 붙인 같은 디렉토리의 경로 (`config.toml.lock` 등)
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// 디렉토리를 만들 때 권한을 0700으로 제한
+fn create_private_dir(path: &Path) -> Result<()> {
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(0o700);
+    }
+    builder.create(path)?;
+    Ok(())
+}
+
+/// 같은 디렉토리의 임시 파일(권한 0600)에 쓰고 fsync한 뒤 원래 경로로 rename
+pub(crate) fn write_atomic(path: &Path, content: &[u8]) -> Result<()> {
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    create_private_dir(parent)?;
+
+    let temp_path = with_suffix(path, &format!(".tmp.{}", std::process::id()));
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let result = options.open(&temp_path).and_then(|mut file| {
+        file.write_all(content)?;
+        file.sync_all()?;
+        fs::rename(&temp_path, path)
+    });
+    if let Err(e) = result {
+        let _ = fs::remove_file(&temp_path);
+        return Err(e.into());
+    }
+
+    // rename 자체가 디스크에 반영되도록 디렉토리도 fsync
+    #[cfg(unix)]
+    fs::File::open(parent)?.sync_all()?;
+    Ok(())
+}
+
+/// 설정 파일 내용을 형식 보존용 문서로 해석
+///
+/// 문법 오류는 serde 쪽 오류 메시지(줄, 열 표시)가 더 자세하므로 그것으로 보고
+fn parse_document(content: &str) -> Result<DocumentMut> {
+    content.parse::<DocumentMut>().map_err(|_| {
+        toml::from_str::<toml::Table>(content)
+            .err()
+            .map(Error::Config)
+            .unwrap_or_else(|| Error::Other(t!("error.config_parse")))
+    })
+}
+
+/// 변환 전 원본을 `<설정 파일>.v<버전>.bak`(이미 있으면 뒤에 번호)으로 복사 (권한 0600)
+fn write_backup(path: &Path, version: u32, content: &[u8]) -> Result<PathBuf> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let base = with_suffix(path, &format!(".v{}.bak", version));
+    let mut backup = base.clone();
+    for n in 1.. {
+        match options.open(&backup) {
+            Ok(mut file) => {
+                file.write_all(content)?;
+                file.sync_all()?;
+                break;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                backup = PathBuf::from(format!("{}.{}", base.display(), n));
+            }
+            Err(e) => return Err(e.into()),
         }
     }
+    Ok(backup)
+}
+
+/// 그룹이나 다른 사용자가 읽을 수 있는 설정 파일이면 경고 (암호화된 PAT가 들어 있음)
+fn warn_if_readable_by_others(path: &Path) -> Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(path)?.permissions().mode() & 0o777;
+        if mode & 0o077 != 0 {
+            report!(
+                Warning,
+                "{}",
+                t!(
+                    "config.permissions_too_open",
+                    path.display(),
+                    format!("{:o}", mode)
+                )
+            );
+            report!(Hint(1), "chmod 600 {}", path.display());
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+pub(crate) fn expand_path(path: &str) -> String {
+    if path.starts_with("~/")
+        && let Some(home) = dirs::home_dir()
+    {
+        return path.replace("~", &home.to_string_lossy());
+    }
     path.to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_mappings(mappings: &str) -> Config {
+        toml::from_str(&format!("version = 1\n[path_mappings]\n{}", mappings)).unwrap()
+    }
+
+    fn profile_for<'a>(config: &'a Config, path: &str) -> Option<&'a str> {
+        config.find_profile_for_path(path).map(String::as_str)
+    }
+
+    #[test]
+    fn mapping_matches_whole_path_components() {
+        let config = config_with_mappings("\"/srv/work\" = \"work\"\n\"/srv\" = \"personal\"");
+        assert_eq!(profile_for(&config, "/srv/work"), Some("work"));
+        assert_eq!(profile_for(&config, "/srv/work/api"), Some("work"));
+        // 같은 문자열로 시작하는 형제 디렉토리는 상위 매핑을 따름
+        assert_eq!(
+            profile_for(&config, "/srv/workspace/blog"),
+            Some("personal")
+        );
+    }
+
+    #[test]
+    fn mapping_with_trailing_separator_matches_directory() {
+        let config = config_with_mappings("\"/srv/work/\" = \"work\"");
+        assert_eq!(profile_for(&config, "/srv/work/api"), Some("work"));
+        assert_eq!(profile_for(&config, "/srv/workspace/blog"), None);
+    }
+
+    #[test]
+    fn migrate_rewrites_v0_file_and_keeps_backup() {
+        let dir = env::temp_dir().join(format!("git-switcher-migrate-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let config_path = dir.join("config.toml");
+        let original = "# 내 설정\n[profiles.work]\nname = \"Hong\"\nemail = \"hong@acme.com\"\n";
+        fs::write(&config_path, original).unwrap();
+        set_config_path(config_path.clone());
+
+        let migration = Config::migrate().unwrap().unwrap();
+        assert_eq!(migration.from, 0);
+        assert_eq!(migration.backup, dir.join("config.toml.v0.bak"));
+        assert_eq!(fs::read_to_string(&migration.backup).unwrap(), original);
+        assert_eq!(
+            fs::read_to_string(&config_path).unwrap(),
+            original.replacen("\n", &format!("\nversion = {}\n", CONFIG_VERSION), 1)
+        );
+        // 이미 현재 버전이면 다시 변환하지 않음
+        assert!(Config::migrate().unwrap().is_none());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn git_config_arrays_accept_scalars() {
+        let profile: Profile = toml::from_str(
+            r#"
+            [git_config]
+            "core.autocrlf" = false
+            "remote.origin.push" = ["refs/heads/main", "refs/tags/*"]
+            "test.flags" = [true, false]
+            "test.levels" = [1, "two"]
+            "#,
+        )
+        .unwrap();
+        let entries: BTreeMap<_, _> = profile.git_config_entries().into_iter().collect();
+        assert_eq!(entries["core.autocrlf"], ["false"]);
+        assert_eq!(
+            entries["remote.origin.push"],
+            ["refs/heads/main", "refs/tags/*"]
+        );
+        assert_eq!(entries["test.flags"], ["true", "false"]);
+        assert_eq!(entries["test.levels"], ["1", "two"]);
+
+        // 단일 값처럼 실수는 받지 않음
+        assert!(toml::from_str::<Profile>("[git_config]\n\"a.b\" = [1.5]\n").is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use toml_edit::{DocumentMut, Item, Table, TableLike, Value};
+
+/// 기존 설정 파일 내용에 새로 직렬화한 설정을 반영
+///
+/// 값이 바뀐 키만 고치고 없어진 키는 지우므로, 사용자가 적어 둔 주석과 키 순서는 그대로 남음.
+/// 새로 생긴 키는 같은 테이블의 끝에 직렬화 순서(이름순)대로 추가.
+/// 기존 내용을 해석할 수 없으면 새 내용을 그대로 반환
+pub(crate) fn merge_preserving_format(existing: &str, updated: &str) -> String {
+    let (Ok(mut document), Ok(updated_document)) = (
+        existing.parse::<DocumentMut>(),
+        updated.parse::<DocumentMut>(),
+    ) else {
+        return updated.to_string();
+    };
+
+    merge_table(document.as_table_mut(), updated_document.as_table());
+    document.to_string()
+}
+
+fn merge_table(existing: &mut dyn TableLike, updated: &dyn TableLike) {
+    let removed: Vec<String> = existing
+        .iter()
+        .map(|(key, _)| key.to_string())
+        .filter(|key| !updated.contains_key(key))
+        .collect();
+    for key in removed {
+        existing.remove(&key);
+    }
+
+    for (key, updated_item) in updated.iter() {
+        let Some(item) = existing.get_mut(key) else {
+            existing.insert(key, fresh_item(updated_item));
+            continue;
+        };
+
+        match (item.as_table_like_mut(), updated_item.as_table_like()) {
+            (Some(table), Some(updated_table)) => merge_table(table, updated_table),
+            _ => match (item.as_value_mut(), updated_item.as_value()) {
+                (Some(value), Some(updated_value)) => {
+                    if !values_equal(value, updated_value) {
+                        // 값 뒤의 주석 등 꾸밈은 유지
+                        let decor = value.decor().clone();
+                        *value = updated_value.clone();
+                        *value.decor_mut() = decor;
+                    }
+                }
+                _ => *item = fresh_item(updated_item),
+            },
+        }
+    }
+}
+
+/// 다른 문서의 항목을 위치 정보와 꾸밈 없이 복사
+///
+/// 테이블을 그대로 복사하면 원래 문서에서의 위치가 따라와 엉뚱한 곳에 출력되므로 새로 만듦
+fn fresh_item(item: &Item) -> Item {
+    match item {
+        Item::Table(updated_table) => {
+            let mut table = Table::new();
+            table.set_implicit(updated_table.is_implicit());
+            merge_table(&mut table, updated_table);
+            Item::Table(table)
+        }
+        Item::Value(value) => {
+            let mut value = value.clone();
+            value.decor_mut().clear();
+            Item::Value(value)
+        }
+        other => other.clone(),
+    }
+}
+
+/// 표기 방식(따옴표, 줄바꿈 등)과 관계없이 값이 같은지 비교
+fn values_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::String(a), Value::String(b)) => a.value() == b.value(),
+        (Value::Integer(a), Value::Integer(b)) => a.value() == b.value(),
+        (Value::Float(a), Value::Float(b)) => a.value() == b.value(),
+        (Value::Boolean(a), Value::Boolean(b)) => a.value() == b.value(),
+        (Value::Datetime(a), Value::Datetime(b)) => a.value() == b.value(),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| values_equal(a, b))
+        }
+        (Value::InlineTable(a), Value::InlineTable(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).is_some_and(|b| values_equal(a, b)))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXISTING: &str = r#"# 내 설정
+version = 1
+
+[profiles.work]
+# 회사 계정
+email = "hong@acme.com" # 업무용
+name = "Hong"
+
+[profiles.personal]
+name = "Hong"
+email = "hong@gmail.com"
+
+[path_mappings]
+"~/work" = "work"
+"#;
+
+    #[test]
+    fn unchanged_content_is_kept_byte_for_byte() {
+        let updated = r#"version = 1
+
+[profiles.personal]
+email = "hong@gmail.com"
+name = "Hong"
+
+[profiles.work]
+email = "hong@acme.com"
+name = "Hong"
+
+[path_mappings]
+"~/work" = "work"
+"#;
+        assert_eq!(merge_preserving_format(EXISTING, updated), EXISTING);
+    }
+
+    #[test]
+    fn changes_keep_comments_and_key_order() {
+        let updated = r#"version = 1
+
+[profiles.personal]
+email = "hong@gmail.com"
+name = "Hong"
+
+[profiles.work]
+email = "hong@acme.co.kr"
+name = "Hong"
+ssh_key = "~/.ssh/id_work"
+
+[path_mappings]
+"#;
+        let merged = merge_preserving_format(EXISTING, updated);
+        assert_eq!(
+            merged,
+            r#"# 내 설정
+version = 1
+
+[profiles.work]
+# 회사 계정
+email = "hong@acme.co.kr" # 업무용
+name = "Hong"
+ssh_key = "~/.ssh/id_work"
+
+[profiles.personal]
+name = "Hong"
+email = "hong@gmail.com"
+
+[path_mappings]
+"#
+        );
+    }
+
+    #[test]
+    fn added_then_removed_key_round_trips() {
+        let with_mapping = EXISTING.replace(
+            "\"~/work\" = \"work\"\n",
+            "\"~/work\" = \"work\"\n\"~/oss\" = \"personal\"\n",
+        );
+        let added = merge_preserving_format(EXISTING, &with_mapping);
+        assert_eq!(added, with_mapping);
+        assert_eq!(merge_preserving_format(&added, EXISTING), EXISTING);
+    }
+
+    #[test]
+    fn unparsable_existing_content_is_replaced() {
+        let updated = "version = 1\n";
+        assert_eq!(merge_preserving_format("version = [", updated), updated);
+    }
+}
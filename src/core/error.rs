@@ -1,3 +1,5 @@
+use crate::core::CONFIG_VERSION;
+use crate::t;
 use std::fmt;
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -7,32 +9,262 @@ pub enum Error {
     Io(std::io::Error),
     Config(toml::de::Error),
     SerdeToml(toml::ser::Error),
+    Json(serde_json::Error),
     Git(String),
     NotGitRepo,
     ProfileNotFound(String),
+    ProfileExists(String),
+    /// 경로 매핑이나 다른 프로필의 `extends`가 가리키고 있어 제거할 수 없는 프로필
+    ProfileInUse {
+        name: String,
+        mappings: Vec<String>,
+        children: Vec<String>,
+    },
+    InheritanceCycle(String),
+    IncompleteProfile(String, &'static str),
     ConfigNotFound,
+    /// 시스템/저장소 레이어 파일을 해석할 수 없음 (경로, 원인)
+    LayerParse(String, toml::de::Error),
+    /// 시스템 레이어에서 온 값이라 사용자 설정으로 지우거나 옮길 수 없음 (키)
+    SystemManaged(String),
+    /// 저장소나 팀 정책 위반 (설명)
+    PolicyViolation(String),
+    /// git-switcher 번들 파일이 아니거나 지원하지 않는 번들 버전 (경로, 설명)
+    InvalidBundle(String, String),
+    /// 이 빌드보다 새 버전이거나 알 수 없는 설정 파일 스키마 버전
+    UnsupportedConfigVersion(String),
+    NoMappedProfile(String),
+    /// 다시 쓸 커밋 중 이미 push한 커밋이 있음 (개수)
+    PushedCommits(usize),
+    /// 경로 매핑이 존재하지 않는 프로필을 가리킴 (경로, 프로필)
+    InvalidMapping(String, String),
+    /// GitHub 크리덴셜 저장 실패 (사용자명, 원인)
+    CredentialStore(String, std::io::Error),
+    /// 크리덴셜 삭제 실패 (사용자명@호스트, 원인)
+    CredentialErase(String, std::io::Error),
+    Encryption(String),
+    /// 저장된 PAT 데이터가 손상됨
+    Decryption(String),
+    /// 다른 키로 암호화된 PAT (다른 빌드에서 저장한 설정 등)
+    KeyMismatch,
+    /// 번들의 PAT를 풀 수 없는 암호
+    WrongPassphrase,
+    /// GitHub API 요청 실패 (설명, curl 실행 오류)
+    Network(String, Option<std::io::Error>),
+    HomeNotFound,
+    NoProfiles,
+    NotInteractive,
+    /// 번호 선택 프롬프트에서 추천 프로필 없이 빈 줄을 입력함
+    NoSelection,
     Other(String),
 }
 
+impl Error {
+    /// 팀 정책 위반의 종료 코드 (`verify`처럼 오류를 반환하지 않고 결과를 출력한 뒤 끝낼 때 사용)
+    pub const POLICY_VIOLATION_EXIT_CODE: i32 = 13;
+
+    /// JSON 출력에 사용하는 안정적인 오류 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::Io(_) => "io",
+            Error::Config(_) => "config_parse",
+            Error::SerdeToml(_) => "config_serialize",
+            Error::Json(_) => "json_serialize",
+            Error::Git(_) => "git",
+            Error::PushedCommits(_) => "pushed_commits",
+            Error::NotGitRepo => "not_git_repo",
+            Error::ProfileNotFound(_) => "profile_not_found",
+            Error::ProfileExists(_) => "profile_exists",
+            Error::ProfileInUse { .. } => "profile_in_use",
+            Error::InheritanceCycle(_) => "inheritance_cycle",
+            Error::IncompleteProfile(_, _) => "incomplete_profile",
+            Error::ConfigNotFound => "config_not_found",
+            Error::LayerParse(_, _) => "layer_parse",
+            Error::SystemManaged(_) => "system_managed",
+            Error::PolicyViolation(_) => "policy_violation",
+            Error::InvalidBundle(_, _) => "invalid_bundle",
+            Error::UnsupportedConfigVersion(_) => "unsupported_config_version",
+            Error::NoMappedProfile(_) => "no_mapped_profile",
+            Error::InvalidMapping(_, _) => "invalid_mapping",
+            Error::CredentialStore(_, _) => "credential_store",
+            Error::CredentialErase(_, _) => "credential_erase",
+            Error::Encryption(_) => "encryption",
+            Error::Decryption(_) => "decryption",
+            Error::KeyMismatch => "key_mismatch",
+            Error::WrongPassphrase => "wrong_passphrase",
+            Error::Network(_, _) => "network",
+            Error::HomeNotFound => "home_not_found",
+            Error::NoProfiles => "no_profiles",
+            Error::NotInteractive => "not_interactive",
+            Error::NoSelection => "no_selection",
+            Error::Other(_) => "other",
+        }
+    }
+
+    /// 프로세스 종료 코드
+    ///
+    /// 2는 clap의 인자 오류, 3과 4는 `status`의 MISMATCH/UNMAPPED와 같은 의미
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::NoMappedProfile(_) => 4,
+            Error::NotGitRepo => 5,
+            Error::ProfileNotFound(_) | Error::ProfileExists(_) | Error::ProfileInUse { .. } => 6,
+            Error::Config(_)
+            | Error::ConfigNotFound
+            | Error::LayerParse(_, _)
+            | Error::SystemManaged(_)
+            | Error::InvalidBundle(_, _)
+            | Error::UnsupportedConfigVersion(_)
+            | Error::InheritanceCycle(_)
+            | Error::IncompleteProfile(_, _)
+            | Error::InvalidMapping(_, _) => 7,
+            Error::Git(_) | Error::PushedCommits(_) => 8,
+            Error::CredentialStore(_, _) | Error::CredentialErase(_, _) => 9,
+            Error::Encryption(_)
+            | Error::Decryption(_)
+            | Error::KeyMismatch
+            | Error::WrongPassphrase => 10,
+            Error::Network(_, _) => 11,
+            Error::Io(_) => 12,
+            Error::PolicyViolation(_) => Self::POLICY_VIOLATION_EXIT_CODE,
+            Error::SerdeToml(_)
+            | Error::Json(_)
+            | Error::HomeNotFound
+            | Error::NoProfiles
+            | Error::NotInteractive
+            | Error::NoSelection
+            | Error::Other(_) => 1,
+        }
+    }
+
+    /// 오류를 해결하기 위해 할 일 안내
+    pub fn hint(&self) -> Option<String> {
+        let hint = match self {
+            Error::Config(_) | Error::LayerParse(_, _) => t!("hint.config_parse"),
+            Error::SystemManaged(_) => t!("hint.system_managed"),
+            Error::PolicyViolation(_) => t!("hint.policy_violation"),
+            Error::NotGitRepo => t!("hint.not_git_repo"),
+            Error::ProfileNotFound(_) => t!("hint.profile_not_found"),
+            Error::ProfileExists(name) => t!("hint.profile_exists", name),
+            Error::ProfileInUse { name, children, .. } if children.is_empty() => {
+                t!("hint.profile_in_use_mappings", name)
+            }
+            Error::ProfileInUse { .. } => t!("hint.profile_in_use_children"),
+            Error::InheritanceCycle(_) => t!("hint.inheritance_cycle"),
+            Error::IncompleteProfile(name, field) => {
+                let flag = match *field {
+                    "name" => "user-name",
+                    "signingkey" => "signing-key",
+                    field => field,
+                };
+                t!("hint.incomplete_profile", name, flag)
+            }
+            Error::UnsupportedConfigVersion(_) => t!("hint.unsupported_config_version"),
+            Error::NoMappedProfile(_) => t!("hint.no_mapped_profile"),
+            Error::PushedCommits(_) => t!("hint.pushed_commits"),
+            Error::InvalidMapping(path, _) => t!("hint.invalid_mapping", path),
+            Error::CredentialStore(_, _) => t!("hint.credential_store"),
+            Error::CredentialErase(_, _) => t!("hint.credential_erase"),
+            Error::Decryption(_) | Error::KeyMismatch => t!("hint.reenter_pat"),
+            Error::WrongPassphrase => t!("hint.wrong_passphrase"),
+            Error::Network(_, _) => t!("hint.network"),
+            Error::HomeNotFound => t!("hint.home_not_found"),
+            Error::NoProfiles => t!("hint.no_profiles"),
+            Error::NotInteractive => t!("hint.not_interactive"),
+            Error::NoSelection => t!("hint.no_selection"),
+            _ => return None,
+        };
+        Some(hint)
+    }
+
+    /// `source()`를 따라간 원인 메시지 목록
+    pub fn causes(&self) -> Vec<String> {
+        let mut causes = Vec::new();
+        let mut source = std::error::Error::source(self);
+        while let Some(err) = source {
+            causes.push(err.to_string());
+            source = err.source();
+        }
+        causes
+    }
+}
+
+/// `{:#}`로 출력하면 원인까지 `: `로 이어서 표시
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Error::Io(err) => write!(f, "IO 에러: {}", err),
-            Error::Config(err) => write!(f, "설정 파일 파싱 에러: {}", err),
-            Error::SerdeToml(err) => write!(f, "TOML 직렬화 에러: {}", err),
-            Error::Git(msg) => write!(f, "Git 에러: {}", msg),
-            Error::NotGitRepo => write!(f, "현재 디렉토리는 Git 저장소가 아닙니다"),
-            Error::ProfileNotFound(name) => write!(f, "프로필 '{}'을 찾을 수 없습니다", name),
-            Error::ConfigNotFound => write!(
-                f,
-                "설정 파일이 없습니다. 'git-switcher init' 명령으로 초기화하세요"
-            ),
-            Error::Other(msg) => write!(f, "{}", msg),
+        let message = match self {
+            Error::Io(_) => t!("error.io"),
+            Error::Config(_) => t!("error.config_parse"),
+            Error::SerdeToml(_) => t!("error.config_serialize"),
+            Error::Json(_) => t!("error.json_serialize"),
+            Error::Git(msg) => t!("error.git", msg),
+            Error::NotGitRepo => t!("error.not_git_repo"),
+            Error::ProfileNotFound(name) => t!("error.profile_not_found", name),
+            Error::ProfileExists(name) => t!("error.profile_exists", name),
+            Error::ProfileInUse {
+                name,
+                mappings,
+                children,
+            } => {
+                let mut references = Vec::new();
+                if !mappings.is_empty() {
+                    references.push(t!("error.profile_in_use_mappings", mappings.join(", ")));
+                }
+                if !children.is_empty() {
+                    references.push(t!("error.profile_in_use_children", children.join(", ")));
+                }
+                t!("error.profile_in_use", name, references.join("; "))
+            }
+            Error::InheritanceCycle(chain) => t!("error.inheritance_cycle", chain),
+            Error::IncompleteProfile(name, field) => t!("error.incomplete_profile", name, field),
+            Error::ConfigNotFound => t!("error.config_not_found"),
+            Error::LayerParse(path, _) => t!("error.layer_parse", path),
+            Error::SystemManaged(key) => t!("error.system_managed", key),
+            Error::PolicyViolation(msg) => msg.clone(),
+            Error::InvalidBundle(path, reason) => t!("error.invalid_bundle", path, reason),
+            Error::UnsupportedConfigVersion(version) => {
+                t!("error.unsupported_config_version", version, CONFIG_VERSION)
+            }
+            Error::NoMappedProfile(path) => t!("error.no_mapped_profile", path),
+            Error::PushedCommits(count) => t!("error.pushed_commits", count),
+            Error::InvalidMapping(path, profile) => t!("error.invalid_mapping", path, profile),
+            Error::CredentialStore(username, _) => t!("error.credential_store", username),
+            Error::CredentialErase(account, _) => t!("error.credential_erase", account),
+            Error::Encryption(msg) => t!("error.encryption", msg),
+            Error::Decryption(msg) => t!("error.decryption", msg),
+            Error::KeyMismatch => t!("error.key_mismatch"),
+            Error::WrongPassphrase => t!("error.wrong_passphrase"),
+            Error::Network(msg, _) => t!("error.network", msg),
+            Error::HomeNotFound => t!("error.home_not_found"),
+            Error::NoProfiles => t!("error.no_profiles"),
+            Error::NotInteractive => t!("error.not_interactive"),
+            Error::NoSelection => t!("error.no_selection"),
+            Error::Other(msg) => msg.clone(),
+        };
+        write!(f, "{}", message)?;
+
+        if f.alternate() {
+            for cause in self.causes() {
+                write!(f, ": {}", cause)?;
+            }
         }
+        Ok(())
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Config(err) | Error::LayerParse(_, err) => Some(err),
+            Error::SerdeToml(err) => Some(err),
+            Error::Json(err) => Some(err),
+            Error::CredentialStore(_, err) | Error::CredentialErase(_, err) => Some(err),
+            Error::Network(_, Some(err)) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl From<std::io::Error> for Error {
     fn from(err: std::io::Error) -> Self {
@@ -46,8 +278,22 @@ impl From<toml::de::Error> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err)
+    }
+}
+
 impl From<toml::ser::Error> for Error {
     fn from(err: toml::ser::Error) -> Self {
         Error::SerdeToml(err)
     }
 }
+
+impl From<dialoguer::Error> for Error {
+    fn from(err: dialoguer::Error) -> Self {
+        match err {
+            dialoguer::Error::IO(err) => Error::Io(err),
+        }
+    }
+}
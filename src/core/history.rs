@@ -0,0 +1,289 @@
+use crate::core::{Config, Error, Profile, Result};
+use crate::git::{CommitInfo, GitConfig};
+use crate::{report, t};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// 범위를 지정하지 않았을 때 확인하는 커밋 (원격 추적 브랜치에 없는 커밋)
+const UNPUSHED: &[&str] = &["HEAD", "--not", "--remotes"];
+
+/// 기대하는 신원과 다른 커밋
+#[derive(Serialize)]
+pub struct MismatchedCommit {
+    #[serde(flatten)]
+    pub commit: CommitInfo,
+    /// 이메일이 다른 쪽 (`author`, `committer`)
+    pub mismatched: Vec<&'static str>,
+    /// 원격 추적 브랜치에서 도달할 수 있는(이미 push한) 커밋인지
+    pub pushed: bool,
+}
+
+/// `git-switcher audit` 결과
+#[derive(Serialize)]
+pub struct AuditReport {
+    pub repo_path: String,
+    /// 확인한 범위 (`None`이면 push하지 않은 커밋)
+    pub range: Option<String>,
+    pub profile_name: String,
+    pub expected_email: String,
+    /// 확인한 커밋 수
+    pub checked: usize,
+    pub commits: Vec<MismatchedCommit>,
+}
+
+impl AuditReport {
+    pub fn collect(config: &Config, range: Option<&str>, profile: Option<String>) -> Result<Self> {
+        let (profile_name, profile) = expected_profile(config, profile)?;
+        let revisions = match range {
+            Some(range) => vec![range],
+            None => UNPUSHED.to_vec(),
+        };
+        let commits = GitConfig::list_commits(&revisions)?;
+        let checked = commits.len();
+
+        let mut mismatched = Vec::new();
+        for commit in commits {
+            let sides = mismatched_sides(&commit, &profile.email);
+            if sides.is_empty() {
+                continue;
+            }
+            let pushed = GitConfig::is_pushed(&commit.commit)?;
+            mismatched.push(MismatchedCommit {
+                commit,
+                mismatched: sides,
+                pushed,
+            });
+        }
+
+        Ok(AuditReport {
+            repo_path: GitConfig::get_current_directory()?,
+            range: range.map(str::to_string),
+            profile_name,
+            expected_email: profile.email,
+            checked,
+            commits: mismatched,
+        })
+    }
+
+    /// 다른 신원의 커밋이 있으면 `status`의 MISMATCH와 같은 3
+    pub fn exit_code(&self) -> i32 {
+        if self.commits.is_empty() { 0 } else { 3 }
+    }
+}
+
+/// 다시 쓴 커밋 하나
+#[derive(Serialize)]
+pub struct RewrittenCommit {
+    pub old: String,
+    pub new: String,
+    pub subject: String,
+    /// 작성자/커미터를 프로필의 신원으로 바꿨는지 (아니면 부모만 바뀜)
+    pub reassigned: bool,
+}
+
+/// `git-switcher fix-authors` 계획
+///
+/// 기대하는 신원과 다른 커밋은 작성자/커미터를 프로필의 신원으로 바꾸고, 그 뒤의 커밋은
+/// 부모만 바꿔 다시 만듦. 트리는 그대로이므로 작업 트리와 인덱스는 바뀌지 않음
+pub struct AuthorFix {
+    pub profile_name: String,
+    pub profile: Profile,
+    /// `--base`로 지정한 기준 커밋 (`None`이면 push하지 않은 커밋)
+    pub base: Option<String>,
+    /// 오래된 것부터 정렬한 범위 안의 커밋
+    commits: Vec<CommitInfo>,
+    /// 신원을 바꿀 커밋
+    pub mismatched: Vec<CommitInfo>,
+    /// 다시 만들어질 커밋 중 이미 push한 커밋
+    pub pushed: Vec<String>,
+}
+
+impl AuthorFix {
+    pub fn plan(config: &Config, base: Option<&str>, profile: Option<String>) -> Result<Self> {
+        if GitConfig::operation_in_progress()? {
+            return Err(Error::Git(t!("history.operation_in_progress")));
+        }
+        let (profile_name, profile) = expected_profile(config, profile)?;
+
+        let range = base.map(|base| format!("{}..HEAD", base));
+        let mut revisions = vec!["--topo-order", "--reverse"];
+        match &range {
+            Some(range) => revisions.push(range),
+            None => revisions.extend(UNPUSHED),
+        }
+        let commits = GitConfig::list_commits(&revisions)?;
+
+        let mismatched: Vec<CommitInfo> = commits
+            .iter()
+            .filter(|commit| !mismatched_sides(commit, &profile.email).is_empty())
+            .cloned()
+            .collect();
+
+        // 신원을 바꾸는 커밋과 그 자손은 모두 새로 만들어지므로 push 여부 확인
+        let mut changed: HashSet<&str> = HashSet::new();
+        let mut pushed = Vec::new();
+        for commit in &commits {
+            let reassigned = mismatched.iter().any(|m| m.commit == commit.commit);
+            let reparented = commit
+                .parents
+                .iter()
+                .any(|parent| changed.contains(parent.as_str()));
+            if reassigned || reparented {
+                changed.insert(&commit.commit);
+                if GitConfig::is_pushed(&commit.commit)? {
+                    pushed.push(commit.commit.clone());
+                }
+            }
+        }
+
+        Ok(AuthorFix {
+            profile_name,
+            profile,
+            base: base.map(str::to_string),
+            commits,
+            mismatched,
+            pushed,
+        })
+    }
+
+    /// 이미 push한 커밋을 다시 써야 하면 `force` 없이는 거부
+    pub fn check_pushed(&self, force: bool) -> Result<()> {
+        if !self.pushed.is_empty() && !force {
+            return Err(Error::PushedCommits(self.pushed.len()));
+        }
+        Ok(())
+    }
+
+    /// 커밋을 다시 쓰고 HEAD를 옮김 (`sign`이면 프로필의 서명 키로 다시 서명)
+    ///
+    /// 이전 HEAD는 `ORIG_HEAD`에 남겨 `git reset --keep ORIG_HEAD`로 되돌릴 수 있게 함
+    pub fn apply(&self, sign: bool, force: bool) -> Result<Vec<RewrittenCommit>> {
+        self.check_pushed(force)?;
+        let signing_key =
+            if sign {
+                Some(self.profile.signingkey.as_deref().ok_or_else(|| {
+                    Error::IncompleteProfile(self.profile_name.clone(), "signingkey")
+                })?)
+            } else {
+                None
+            };
+        if self.mismatched.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let head = GitConfig::rev_parse("HEAD")?;
+        let mut replaced: HashMap<String, String> = HashMap::new();
+        let mut rewritten = Vec::new();
+        let mut dropped_signatures = 0;
+
+        for commit in &self.commits {
+            let reassigned = self.mismatched.iter().any(|m| m.commit == commit.commit);
+            let parents: Vec<String> = commit
+                .parents
+                .iter()
+                .map(|parent| replaced.get(parent).unwrap_or(parent).clone())
+                .collect();
+            if !reassigned && parents == commit.parents {
+                continue;
+            }
+
+            let (message, signed) = GitConfig::read_commit_message(&commit.commit)?;
+            if signed && signing_key.is_none() {
+                dropped_signatures += 1;
+            }
+            let (name, email) = if reassigned {
+                (self.profile.name.as_str(), self.profile.email.as_str())
+            } else {
+                (commit.author_name.as_str(), commit.author_email.as_str())
+            };
+            let (committer_name, committer_email) = if reassigned {
+                (self.profile.name.as_str(), self.profile.email.as_str())
+            } else {
+                (
+                    commit.committer_name.as_str(),
+                    commit.committer_email.as_str(),
+                )
+            };
+            let envs = [
+                ("GIT_AUTHOR_NAME", name),
+                ("GIT_AUTHOR_EMAIL", email),
+                ("GIT_AUTHOR_DATE", commit.author_date.as_str()),
+                ("GIT_COMMITTER_NAME", committer_name),
+                ("GIT_COMMITTER_EMAIL", committer_email),
+                ("GIT_COMMITTER_DATE", commit.committer_date.as_str()),
+            ];
+            let new = GitConfig::commit_tree(&commit.tree, &parents, &message, &envs, signing_key)?;
+            report!(
+                Debug(1),
+                "{} -> {} {}",
+                short(&commit.commit),
+                short(&new),
+                commit.subject
+            );
+
+            replaced.insert(commit.commit.clone(), new.clone());
+            rewritten.push(RewrittenCommit {
+                old: commit.commit.clone(),
+                new,
+                subject: commit.subject.clone(),
+                reassigned,
+            });
+        }
+
+        if dropped_signatures > 0 {
+            report!(
+                Warning,
+                "{}",
+                t!("fix_authors.signatures_dropped", dropped_signatures)
+            );
+        }
+
+        if let Some(new_head) = replaced.get(&head) {
+            GitConfig::update_ref("ORIG_HEAD", &head, None, "git-switcher fix-authors")?;
+            GitConfig::update_ref("HEAD", new_head, Some(&head), "git-switcher fix-authors")?;
+        }
+        Ok(rewritten)
+    }
+}
+
+/// 표시용 짧은 커밋 ID
+pub fn short(commit: &str) -> &str {
+    &commit[..commit.len().min(7)]
+}
+
+/// `--profile`, 없으면 경로 매핑(저장소의 `.git-switcher.toml` 포함)이 기대하는 프로필
+fn expected_profile(config: &Config, profile: Option<String>) -> Result<(String, Profile)> {
+    if !GitConfig::is_git_repo() {
+        return Err(Error::NotGitRepo);
+    }
+    let profile_name = match profile {
+        Some(profile_name) => profile_name,
+        None => {
+            let current_path = GitConfig::get_current_directory()?;
+            let (mapped_path, profile_name) = config
+                .find_mapping_for_path(&current_path)
+                .ok_or_else(|| Error::NoMappedProfile(current_path.clone()))?;
+            if !config.profiles.contains_key(profile_name) {
+                return Err(Error::InvalidMapping(
+                    mapped_path.clone(),
+                    profile_name.clone(),
+                ));
+            }
+            profile_name.clone()
+        }
+    };
+    let profile = config.resolve_profile(&profile_name)?;
+    Ok((profile_name, profile))
+}
+
+/// 기대하는 이메일과 다른 쪽 (대소문자 무시)
+fn mismatched_sides(commit: &CommitInfo, email: &str) -> Vec<&'static str> {
+    let mut sides = Vec::new();
+    if !commit.author_email.eq_ignore_ascii_case(email) {
+        sides.push("author");
+    }
+    if !commit.committer_email.eq_ignore_ascii_case(email) {
+        sides.push("committer");
+    }
+    sides
+}
@@ -0,0 +1,299 @@
+use crate::core::{Error, Result, migration, policy};
+use crate::git::GitConfig;
+use crate::{report, t};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+use toml_edit::DocumentMut;
+
+/// 저장소 레이어 파일 이름 (작업 트리 최상위에 둠)
+pub const REPO_CONFIG_FILE: &str = ".git-switcher.toml";
+
+/// 시스템 레이어에 둘 수 없는 비밀 값 (사용자 설정에만 저장)
+const SECRET_KEYS: &[&str] = &["encrypted_pat"];
+
+/// 설정 값이 정의된 레이어 (뒤쪽이 우선)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigLayer {
+    /// 팀/시스템 공통 설정 (`/etc/git-switcher/config.toml`)
+    System,
+    /// 사용자 설정 (`config.toml`)
+    User,
+    /// 저장소의 `.git-switcher.toml` (제약만 선언)
+    Repo,
+}
+
+impl fmt::Display for ConfigLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigLayer::System => f.pad("system"),
+            ConfigLayer::User => f.pad("user"),
+            ConfigLayer::Repo => f.pad("repo"),
+        }
+    }
+}
+
+/// 설정 키 경로 (`["profiles", "work", "email"]`)
+pub type KeyPath = Vec<String>;
+
+/// 읽어 들인 시스템 레이어
+pub(crate) struct SystemLayer {
+    pub table: Table,
+}
+
+/// 저장소의 `.git-switcher.toml`
+///
+/// 사용할 프로필과 허용 이메일 도메인 같은 제약만 선언할 수 있고,
+/// 프로필이나 PAT처럼 값을 넣는 키는 해석 단계에서 거부
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RepoPolicy {
+    /// 이 저장소에서 사용해야 하는 프로필
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// 허용하는 이메일 도메인 (하위 도메인 포함, 비어 있으면 제한 없음)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_email_domains: Vec<String>,
+}
+
+impl RepoPolicy {
+    /// 이메일이 허용 도메인에 속하는지
+    pub fn allows_email(&self, email: &str) -> bool {
+        self.allowed_email_domains.is_empty()
+            || policy::email_in_domains(email, &self.allowed_email_domains)
+    }
+}
+
+/// 읽어 들인 저장소 레이어
+#[derive(Clone, Debug)]
+pub struct RepoLayer {
+    /// 작업 트리 최상위 디렉토리
+    pub root: String,
+    /// `.git-switcher.toml` 경로
+    pub path: String,
+    pub policy: RepoPolicy,
+}
+
+/// `config show`에 표시하는 레이어 파일 정보
+#[derive(Serialize)]
+pub struct LayerInfo {
+    pub layer: ConfigLayer,
+    /// 저장소 밖이면 저장소 레이어 경로는 없음
+    pub path: Option<String>,
+    /// 파일이 있어서 병합에 사용했는지
+    pub loaded: bool,
+}
+
+/// 병합된 설정 값 하나와 그 값이 정의된 레이어
+#[derive(Serialize)]
+pub struct Setting {
+    pub key: String,
+    pub value: Value,
+    pub layer: ConfigLayer,
+}
+
+impl Setting {
+    /// 비밀 값은 표시하지 않도록 가림
+    pub(crate) fn new(path: &[String], value: Value, layer: ConfigLayer) -> Self {
+        let secret = path
+            .last()
+            .is_some_and(|key| SECRET_KEYS.contains(&key.as_str()));
+        Setting {
+            key: display_key(path),
+            value: if secret {
+                Value::String("********".to_string())
+            } else {
+                value
+            },
+            layer,
+        }
+    }
+}
+
+/// 시스템 레이어 파일 경로 (`GIT_SWITCHER_SYSTEM_CONFIG`로 바꿀 수 있음)
+pub fn system_config_path() -> PathBuf {
+    std::env::var_os("GIT_SWITCHER_SYSTEM_CONFIG")
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("/etc/git-switcher/config.toml"))
+}
+
+/// 시스템 레이어 읽기 (없으면 `None`)
+///
+/// 보통 쓰기 권한이 없으므로 오래된 스키마는 메모리에서만 변환하고, 비밀 값은 경고 후 무시
+pub(crate) fn load_system() -> Result<Option<SystemLayer>> {
+    let path = system_config_path();
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&path)?;
+    let parse_error = |err| Error::LayerParse(path.display().to_string(), err);
+    // 문법 오류는 위치가 표시되는 toml 쪽 오류로 먼저 보고
+    toml::from_str::<Table>(&content).map_err(parse_error)?;
+    let mut document = content
+        .parse::<DocumentMut>()
+        .map_err(|err| Error::Other(err.to_string()))?;
+    let version = migration::document_version(&document)?;
+    migration::migrate(&mut document, version);
+
+    let mut table: Table = toml::from_str(&document.to_string()).map_err(parse_error)?;
+    table.remove("version");
+    let mut removed = Vec::new();
+    strip_secrets(&mut table, &mut Vec::new(), &mut removed);
+    for key in removed {
+        report!(
+            Warning,
+            "{}",
+            t!("layers.secret_ignored", key, path.display())
+        );
+    }
+    Ok(Some(SystemLayer { table }))
+}
+
+/// 현재 디렉토리가 저장소 안이면 최상위의 `.git-switcher.toml` 읽기
+pub(crate) fn load_repo() -> Result<Option<RepoLayer>> {
+    let Ok(root) = GitConfig::get_repo_root() else {
+        return Ok(None);
+    };
+    let path = Path::new(&root).join(REPO_CONFIG_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&path)?;
+    let policy: RepoPolicy = toml::from_str(&content)
+        .map_err(|err| Error::LayerParse(path.display().to_string(), err))?;
+    Ok(Some(RepoLayer {
+        root,
+        path: path.display().to_string(),
+        policy,
+    }))
+}
+
+/// 값 아래의 모든 말단 키를 `layer`에서 온 것으로 기록 (빈 테이블은 값이 아니므로 제외)
+pub(crate) fn record_leaves(
+    value: &Value,
+    path: &mut KeyPath,
+    layer: ConfigLayer,
+    origins: &mut BTreeMap<KeyPath, ConfigLayer>,
+) {
+    match value {
+        Value::Table(table) => {
+            for (key, value) in table {
+                path.push(key.clone());
+                record_leaves(value, path, layer, origins);
+                path.pop();
+            }
+        }
+        _ => {
+            origins.insert(path.clone(), layer);
+        }
+    }
+}
+
+/// `target`에 없는 키만 아래 레이어(`lower`)에서 채움 (테이블은 키 단위로 병합)
+pub(crate) fn merge_missing(
+    target: &mut Table,
+    lower: &Table,
+    path: &mut KeyPath,
+    layer: ConfigLayer,
+    origins: &mut BTreeMap<KeyPath, ConfigLayer>,
+) {
+    for (key, lower_value) in lower {
+        path.push(key.clone());
+        match (target.get_mut(key), lower_value) {
+            (Some(Value::Table(table)), Value::Table(lower_table)) => {
+                merge_missing(table, lower_table, path, layer, origins);
+            }
+            (Some(_), _) => {}
+            (None, value) => {
+                record_leaves(value, path, layer, origins);
+                target.insert(key.clone(), value.clone());
+            }
+        }
+        path.pop();
+    }
+}
+
+/// 저장할 내용에서 아래 레이어 값을 그대로 물려받은 키를 제거
+///
+/// 사용자가 바꾼 값은 남겨서 사용자 설정이 아래 레이어를 덮어쓰게 함
+pub(crate) fn strip_inherited(
+    table: &mut Table,
+    lower: &Table,
+    origins: &BTreeMap<KeyPath, ConfigLayer>,
+    layer: ConfigLayer,
+) {
+    for (path, _) in origins.iter().filter(|(_, origin)| **origin == layer) {
+        if lookup(table, path).is_some() && lookup(table, path) == lookup(lower, path) {
+            remove_path(table, path);
+        }
+    }
+}
+
+pub(crate) fn lookup<'a>(table: &'a Table, path: &[String]) -> Option<&'a Value> {
+    let (last, parents) = path.split_last()?;
+    let mut table = table;
+    for key in parents {
+        table = table.get(key)?.as_table()?;
+    }
+    table.get(last)
+}
+
+/// 키를 지우고, 그 때문에 비게 된 테이블도 지움
+fn remove_path(table: &mut Table, path: &[String]) -> bool {
+    match path {
+        [] => false,
+        [last] => table.remove(last).is_some(),
+        [first, rest @ ..] => {
+            let Some(Value::Table(inner)) = table.get_mut(first) else {
+                return false;
+            };
+            let removed = remove_path(inner, rest);
+            if removed && inner.is_empty() {
+                table.remove(first);
+            }
+            removed
+        }
+    }
+}
+
+fn strip_secrets(table: &mut Table, path: &mut KeyPath, removed: &mut Vec<String>) {
+    for key in SECRET_KEYS {
+        if table.remove(*key).is_some() {
+            path.push(key.to_string());
+            removed.push(display_key(path));
+            path.pop();
+        }
+    }
+    for (key, value) in table.iter_mut() {
+        if let Value::Table(inner) = value {
+            path.push(key.clone());
+            strip_secrets(inner, path, removed);
+            path.pop();
+        }
+    }
+}
+
+/// 표시용 키 이름 (`profiles.work.email`, 특수 문자가 든 키는 따옴표)
+pub fn display_key(path: &[String]) -> String {
+    path.iter()
+        .map(|key| {
+            if !key.is_empty()
+                && key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                key.clone()
+            } else {
+                format!("{:?}", key)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
@@ -0,0 +1,149 @@
+use crate::core::{Error, Result};
+use toml_edit::{DocumentMut, RawString, Table, value};
+
+/// 이 빌드가 읽고 쓰는 설정 파일 스키마 버전
+///
+/// `version` 키가 없는 파일은 버전이 도입되기 전의 0으로 취급
+pub const CONFIG_VERSION: u32 = 1;
+
+/// `config migrate --check`에서 변환이 필요할 때의 종료 코드 (오류의 종료 코드와 겹치지 않음)
+pub const MIGRATION_NEEDED_EXIT_CODE: i32 = 14;
+
+/// 버전 N 문서를 N+1로 올리는 함수들 (인덱스가 시작 버전)
+///
+/// 스키마를 바꿀 때는 여기에 단계를 추가하고 `CONFIG_VERSION`을 올림.
+/// 문서를 직접 고치므로 사용자가 적어 둔 주석은 그대로 남음
+const MIGRATIONS: &[fn(&mut DocumentMut)] = &[migrate_v0_to_v1];
+
+/// 문서에 적힌 스키마 버전 (이 빌드보다 새 버전이면 오류)
+pub(crate) fn document_version(document: &DocumentMut) -> Result<u32> {
+    let Some(item) = document.get("version") else {
+        return Ok(0);
+    };
+
+    let version = item
+        .as_integer()
+        .and_then(|version| u32::try_from(version).ok())
+        .ok_or_else(|| Error::UnsupportedConfigVersion(item.to_string().trim().to_string()))?;
+    if version > CONFIG_VERSION {
+        return Err(Error::UnsupportedConfigVersion(version.to_string()));
+    }
+    Ok(version)
+}
+
+/// `from` 버전 문서를 현재 버전까지 차례로 올림
+pub(crate) fn migrate(document: &mut DocumentMut, from: u32) {
+    for step in &MIGRATIONS[from as usize..] {
+        step(document);
+    }
+    let added = !document.contains_key("version");
+    document["version"] = value(i64::from(CONFIG_VERSION));
+    if added {
+        // 새로 넣은 키는 맨 앞에 오도록 (나머지 순서는 유지)
+        let table = document.as_table_mut();
+        table.sort_values_by(|a, _, b, _| (a.get() != "version").cmp(&(b.get() != "version")));
+
+        // 원래 맨 앞 항목 위에 있던 파일 머리 주석은 계속 파일 맨 위에 남도록 옮김
+        if let Some(prefix) = take_header_comment(table)
+            && let Some((mut version, _)) = table.get_key_value_mut("version")
+        {
+            version.leaf_decor_mut().set_prefix(prefix);
+        }
+    }
+}
+
+/// 파일 맨 앞 항목 위의 주석을 떼어 냄
+///
+/// `version`을 뺀 최상위 값이 있으면 그 첫 키, 없으면 가장 먼저 나오는 테이블 헤더
+fn take_header_comment(table: &mut Table) -> Option<RawString> {
+    if let Some((mut first, _)) = table
+        .iter_mut()
+        .find(|(key, item)| item.is_value() && key.get() != "version")
+    {
+        let prefix = first.leaf_decor().prefix().cloned();
+        first.leaf_decor_mut().set_prefix("");
+        return prefix;
+    }
+
+    let position = first_table_position(table)?;
+    let first = table_at_position(table, position)?;
+    let prefix = first.decor().prefix().cloned();
+    first.decor_mut().set_prefix("");
+    prefix
+}
+
+/// 하위 테이블까지 포함해 가장 먼저 나오는 헤더의 위치 (헤더 없이 `a.b` 형태로만 쓰인 테이블은 제외)
+fn first_table_position(table: &Table) -> Option<isize> {
+    table
+        .iter()
+        .filter_map(|(_, item)| item.as_table())
+        .flat_map(|table| {
+            let own = (!table.is_implicit()).then(|| table.position()).flatten();
+            [own, first_table_position(table)]
+        })
+        .flatten()
+        .min()
+}
+
+fn table_at_position(table: &mut Table, position: isize) -> Option<&mut Table> {
+    for (_, item) in table.iter_mut() {
+        let Some(table) = item.as_table_mut() else {
+            continue;
+        };
+        if !table.is_implicit() && table.position() == Some(position) {
+            return Some(table);
+        }
+        if let Some(found) = table_at_position(table, position) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+/// 0 -> 1: 내용은 그대로 두고 `version` 키만 추가
+fn migrate_v0_to_v1(_document: &mut DocumentMut) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> DocumentMut {
+        content.parse().unwrap()
+    }
+
+    #[test]
+    fn document_version_reads_and_rejects() {
+        assert_eq!(document_version(&parse("language = \"en\"\n")).unwrap(), 0);
+        assert_eq!(document_version(&parse("version = 1\n")).unwrap(), 1);
+        assert!(matches!(
+            document_version(&parse("version = 2\n")),
+            Err(Error::UnsupportedConfigVersion(version)) if version == "2"
+        ));
+        assert!(matches!(
+            document_version(&parse("version = \"one\"\n")),
+            Err(Error::UnsupportedConfigVersion(_))
+        ));
+        assert!(document_version(&parse("version = -1\n")).is_err());
+    }
+
+    #[test]
+    fn migrate_v0_adds_version_below_header_comment() {
+        let mut document =
+            parse("# 내 설정\nlanguage = \"en\" # 영어\n\n[profiles.work]\nname = \"Hong\"\n");
+        migrate(&mut document, 0);
+        assert_eq!(
+            document.to_string(),
+            "# 내 설정\nversion = 1\nlanguage = \"en\" # 영어\n\n[profiles.work]\nname = \"Hong\"\n"
+        );
+    }
+
+    #[test]
+    fn migrate_v0_with_only_tables() {
+        let mut document = parse("# 내 설정\n[profiles.work]\nname = \"Hong\"\n");
+        migrate(&mut document, 0);
+        assert_eq!(
+            document.to_string(),
+            "# 내 설정\nversion = 1\n[profiles.work]\nname = \"Hong\"\n"
+        );
+    }
+}
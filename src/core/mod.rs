@@ -1,7 +1,26 @@
+pub mod activity;
+pub mod bundle;
 pub mod config;
+mod document;
 pub mod error;
+pub mod history;
+pub mod layers;
+mod migration;
+pub mod policy;
 pub mod profile;
+pub mod status;
+pub mod validate;
 
-pub use config::{Config, Profile};
+pub use activity::{ActivityEvent, ActivityLog};
+pub use bundle::{Bundle, OnConflict};
+pub use config::{
+    Config, GitConfigScalar, GitConfigValue, Migration, Profile, ProfileSource, ResolvedProfile,
+};
 pub use error::{Error, Result};
+pub use history::{AuditReport, AuthorFix};
+pub use layers::{ConfigLayer, RepoPolicy};
+pub use migration::{CONFIG_VERSION, MIGRATION_NEEDED_EXIT_CODE};
+pub use policy::TeamPolicy;
 pub use profile::ProfileManager;
+pub use status::{StatusReport, Verdict};
+pub use validate::{Severity, ValidationReport};
@@ -1,17 +1,35 @@
-use crate::core::{Error, Profile, Result};
+use crate::core::{ActivityEvent, ActivityLog, Error, Profile, Result};
 use crate::git::{CredentialManager, GitConfig};
+use crate::{report, t};
 
 pub struct ProfileManager;
 
 impl ProfileManager {
-    pub fn apply_profile(profile: &Profile, enable_ssh: bool) -> Result<()> {
+    /// 프로필이 적용한 추가 Git 설정 키 목록을 기록하는 로컬 설정 키
+    const MANAGED_KEYS: &'static str = "git-switcher.managedKey";
+
+    /// 현재 저장소에 프로필을 적용하고 결과를 활동 기록에 남김
+    pub fn apply_profile(profile_name: &str, profile: &Profile, enable_ssh: bool) -> Result<()> {
         if !GitConfig::is_git_repo() {
             return Err(Error::NotGitRepo);
         }
+        let result = Self::apply(profile_name, profile, enable_ssh);
+        ActivityLog::record(
+            ActivityEvent::Apply,
+            Some(profile_name),
+            None,
+            result.as_ref().err(),
+        );
+        result
+    }
+
+    fn apply(profile_name: &str, profile: &Profile, enable_ssh: bool) -> Result<()> {
+        // 연결된 작업 트리끼리는 로컬 설정을 공유하므로 어디까지 적용되는지 알림
+        let worktree = GitConfig::worktree_state()?;
 
         // 1. 기존 크리덴셜 삭제 (계정 충돌 방지)
-        println!("🔧 기존 크리덴셜 정리 중...");
-        CredentialManager::clear_existing_credentials(profile)?;
+        report!(Step, "{}", t!("apply.clearing_credentials"));
+        CredentialManager::clear_existing_credentials(profile_name, profile)?;
 
         // 2. 기본 Git 설정 적용
         GitConfig::set_user_name(&profile.name)?;
@@ -22,49 +40,71 @@ impl ProfileManager {
             GitConfig::set_signing_key(signing_key)?;
         }
 
+        // 4. 프로필별 추가 Git 설정 적용 (이전 프로필이 남긴 키 정리)
+        Self::apply_extra_git_config(profile)?;
+
         if !enable_ssh && profile.has_pat() {
             // 5. PAT가 있으면 자동으로 크리덴셜 설정
-            CredentialManager::setup_pat_credentials(profile)?;
+            CredentialManager::setup_pat_credentials(profile_name, profile)?;
         }
 
-        println!("✓ 프로필이 적용되었습니다.");
-        println!("  이름: {}", profile.name);
-        println!("  이메일: {}", profile.email);
+        report!(Success, "{}", t!("apply.applied"));
+        report!(Info(1), "{}", t!("field.name", profile.name));
+        report!(Info(1), "{}", t!("field.email", profile.email));
 
         if let Some(key) = &profile.signingkey {
-            println!("  GPG 키: {}", key);
+            report!(Info(1), "{}", t!("field.gpg_key", key));
+        }
+
+        if !profile.git_config.is_empty() {
+            report!(Info(1), "{}", t!("apply.extra_git_config"));
+            for (key, values) in profile.git_config_entries() {
+                report!(Info(2), "{} = {}", key, values.join(", "));
+            }
+        }
+
+        if worktree.per_worktree {
+            report!(Info(1), "{}", t!("apply.per_worktree"));
+        } else if worktree.count > 1 {
+            report!(
+                Warning(1),
+                "{}",
+                t!("apply.shared_worktrees", worktree.count)
+            );
+            report!(Hint(1), "{}", t!("apply.per_worktree_hint"));
         }
 
         if enable_ssh && profile.ssh_key.is_some() {
-            println!("  SSH 연동: 활성화됨");
+            report!(Info(1), "{}", t!("apply.ssh_enabled"));
         } else if profile.has_pat() {
             if let Some(masked_pat) = profile.get_masked_pat() {
-                println!("  🔑 GitHub PAT: {}", masked_pat);
-                println!("  💡 PAT가 자동으로 설정되었습니다. push가 바로 가능합니다!");
+                report!(Info(1), "GitHub PAT: {}", masked_pat);
+                report!(Hint(1), "{}", t!("apply.pat_configured"));
             }
         } else {
-            println!("  💡 다음 push 시 새로운 PAT 입력이 필요합니다");
+            report!(Hint(1), "{}", t!("apply.pat_required"));
         }
 
         Ok(())
     }
 
-    pub fn show_current_profile() -> Result<()> {
-        if !GitConfig::is_git_repo() {
-            return Err(Error::NotGitRepo);
-        }
+    /// 프로필의 `git_config`를 로컬에 설정하고, 이전에 적용된 키 중
+    /// 새 프로필에 없는 키는 제거
+    fn apply_extra_git_config(profile: &Profile) -> Result<()> {
+        let entries = profile.git_config_entries();
 
-        match (GitConfig::get_user_name(), GitConfig::get_user_email()) {
-            (Ok(name), Ok(email)) => {
-                println!("현재 Git 프로필:");
-                println!("  이름: {}", name);
-                println!("  이메일: {}", email);
-            }
-            _ => {
-                println!("현재 저장소에 Git 프로필이 설정되어 있지 않습니다.");
+        let previous_keys = GitConfig::get_local_config_all(Self::MANAGED_KEYS)?;
+        for key in &previous_keys {
+            if !entries.iter().any(|(new_key, _)| new_key == key) {
+                GitConfig::unset_local_config(key)?;
             }
         }
 
-        Ok(())
+        for (key, values) in &entries {
+            GitConfig::set_local_config_all(key, values)?;
+        }
+
+        let managed_keys: Vec<String> = entries.into_iter().map(|(key, _)| key).collect();
+        GitConfig::set_local_config_all(Self::MANAGED_KEYS, &managed_keys)
     }
 }
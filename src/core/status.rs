@@ -0,0 +1,299 @@
+use crate::core::config::expand_path;
+use crate::core::{Config, Error, Profile, Result};
+use crate::git::{ConfigEntry, GitConfig};
+use crate::t;
+use serde::Serialize;
+use std::fmt;
+
+/// 상태에서 확인하는 신원 관련 설정 키
+const IDENTITY_KEYS: [&str; 3] = ["user.name", "user.email", "user.signingkey"];
+
+/// `git-switcher status`의 최종 판정
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Verdict {
+    /// 경로 규칙이 기대하는 프로필과 실제 설정이 일치
+    Ok,
+    /// 기대하는 프로필과 실제 설정이 다름
+    Mismatch,
+    /// 현재 경로에 매핑된 프로필이 없음
+    Unmapped,
+}
+
+impl Verdict {
+    /// 스크립트용 종료 코드 (1은 일반 오류, 2는 잘못된 인자에 사용되므로 피함)
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Verdict::Ok => 0,
+            Verdict::Mismatch => 3,
+            Verdict::Unmapped => 4,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Ok => write!(f, "OK"),
+            Verdict::Mismatch => write!(f, "MISMATCH"),
+            Verdict::Unmapped => write!(f, "UNMAPPED"),
+        }
+    }
+}
+
+/// 한 범위에서 정의된 설정 값과 그 출처
+#[derive(Serialize)]
+pub struct IdentityEntry {
+    pub scope: String,
+    pub origin: String,
+    pub value: String,
+    /// 범위의 기본 설정 파일이 아니라 `include`로 포함된 파일에서 온 값인지
+    pub included: bool,
+}
+
+/// 설정 키 하나에 대해 모든 범위에서 찾은 값 (우선순위 오름차순)
+#[derive(Serialize)]
+pub struct IdentityValue {
+    pub key: &'static str,
+    pub entries: Vec<IdentityEntry>,
+}
+
+impl IdentityValue {
+    /// 실제로 적용되는 값
+    pub fn effective(&self) -> Option<&str> {
+        self.entries.last().map(|entry| entry.value.as_str())
+    }
+}
+
+/// 기대하는 프로필 기준으로 수행한 점검 하나
+#[derive(Serialize)]
+pub struct StatusCheck {
+    pub name: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+#[derive(Serialize)]
+pub struct StatusReport {
+    pub repo_path: String,
+    pub identity: Vec<IdentityValue>,
+    /// 현재 적용된 이름/이메일과 일치하는 프로필
+    pub current_profile: Option<String>,
+    /// 경로 매핑이 기대하는 프로필
+    pub expected_profile: Option<String>,
+    pub checks: Vec<StatusCheck>,
+    pub verdict: Verdict,
+}
+
+impl StatusReport {
+    pub fn collect(config: &Config) -> Result<Self> {
+        if !GitConfig::is_git_repo() {
+            return Err(Error::NotGitRepo);
+        }
+
+        let repo_path = GitConfig::get_current_directory()?;
+        let standard_files = StandardConfigFiles::detect()?;
+
+        let mut identity = Vec::new();
+        for key in IDENTITY_KEYS {
+            let entries = GitConfig::get_config_entries(key)?
+                .into_iter()
+                .map(|entry| standard_files.classify(entry))
+                .collect();
+            identity.push(IdentityValue { key, entries });
+        }
+
+        let effective = |key: &str| {
+            identity
+                .iter()
+                .find(|value| value.key == key)
+                .and_then(|value| value.effective())
+        };
+
+        let current_profile = match (effective("user.name"), effective("user.email")) {
+            (Some(name), Some(email)) => config.find_profile_by_identity(name, email).cloned(),
+            _ => None,
+        };
+        let expected_profile = config.find_profile_for_path(&repo_path).cloned();
+
+        let (checks, verdict) = match &expected_profile {
+            Some(expected) => {
+                let profile = config.resolve_profile(expected)?;
+                let mut checks = Self::check_profile(&profile, effective)?;
+                if let Some(repo) = config.repo_layer()
+                    && !repo.policy.allowed_email_domains.is_empty()
+                {
+                    let email = effective("user.email").unwrap_or_default();
+                    checks.push(StatusCheck {
+                        name: "allowed_email_domains".to_string(),
+                        passed: repo.policy.allows_email(email),
+                        detail: t!(
+                            "status.allowed_email_domains",
+                            repo.policy.allowed_email_domains.join(", "),
+                            repo.path
+                        ),
+                    });
+                }
+                let verdict = if checks.iter().all(|check| check.passed) {
+                    Verdict::Ok
+                } else {
+                    Verdict::Mismatch
+                };
+                (checks, verdict)
+            }
+            None => (Vec::new(), Verdict::Unmapped),
+        };
+
+        Ok(StatusReport {
+            repo_path,
+            identity,
+            current_profile,
+            expected_profile,
+            checks,
+            verdict,
+        })
+    }
+
+    fn check_profile<'a>(
+        profile: &Profile,
+        effective: impl Fn(&str) -> Option<&'a str>,
+    ) -> Result<Vec<StatusCheck>> {
+        let mut checks = Vec::new();
+
+        let mut expected_values = vec![
+            ("user.name", profile.name.as_str()),
+            ("user.email", profile.email.as_str()),
+        ];
+        if let Some(signing_key) = &profile.signingkey {
+            expected_values.push(("user.signingkey", signing_key));
+        }
+        for (key, expected) in expected_values {
+            checks.push(Self::compare(key, expected, effective(key)));
+        }
+
+        for (key, values) in profile.git_config_entries() {
+            let actual = GitConfig::get_local_config_all(&key)?;
+            let passed = actual == values;
+            let detail = if passed {
+                actual.join(", ")
+            } else {
+                let actual = if actual.is_empty() {
+                    t!("status.none")
+                } else {
+                    actual.join(", ")
+                };
+                t!("status.expected_local", values.join(", "), actual)
+            };
+            checks.push(StatusCheck {
+                name: key,
+                passed,
+                detail,
+            });
+        }
+
+        if let Some(github_username) = &profile.github_username
+            && profile.has_pat()
+        {
+            let passed = GitConfig::has_stored_github_credentials(github_username);
+            checks.push(StatusCheck {
+                name: "credentials".to_string(),
+                passed,
+                detail: if passed {
+                    t!("status.credentials_stored", github_username)
+                } else {
+                    t!("status.credentials_missing", github_username)
+                },
+            });
+        }
+
+        if let Some(ssh_key) = &profile.ssh_key {
+            let passed = std::path::Path::new(&expand_path(ssh_key)).exists();
+            let ssh_remotes: Vec<String> = GitConfig::get_remotes()?
+                .into_iter()
+                .filter(|(_, url)| url.starts_with("git@") || url.starts_with("ssh://"))
+                .map(|(name, _)| name)
+                .collect();
+            let mut detail = if passed {
+                t!("status.ssh_key_exists", ssh_key)
+            } else {
+                t!("status.ssh_key_missing", ssh_key)
+            };
+            if ssh_remotes.is_empty() {
+                detail.push_str(&format!(" ({})", t!("status.no_ssh_remotes")));
+            } else {
+                detail.push_str(&format!(
+                    " ({})",
+                    t!("status.ssh_remotes", ssh_remotes.join(", "))
+                ));
+            }
+            checks.push(StatusCheck {
+                name: "ssh".to_string(),
+                passed,
+                detail,
+            });
+        }
+
+        Ok(checks)
+    }
+
+    fn compare(key: &str, expected: &str, actual: Option<&str>) -> StatusCheck {
+        let actual = actual
+            .map(str::to_string)
+            .unwrap_or_else(|| t!("status.none"));
+        let passed = expected == actual;
+        StatusCheck {
+            name: key.to_string(),
+            passed,
+            detail: if passed {
+                actual
+            } else {
+                t!("status.expected_actual", expected, actual)
+            },
+        }
+    }
+}
+
+/// 범위별 기본 설정 파일 (이 외의 파일은 `include`로 포함된 것으로 간주)
+struct StandardConfigFiles {
+    local: Vec<String>,
+    global: Vec<String>,
+}
+
+impl StandardConfigFiles {
+    fn detect() -> Result<Self> {
+        // 연결된 작업 트리의 config.worktree는 공통 디렉토리가 아닌 작업 트리의 git 디렉토리에 있음
+        let local = vec![
+            GitConfig::get_local_config_path()?,
+            GitConfig::get_worktree_config_path()?,
+        ];
+
+        let mut global = Vec::new();
+        if let Some(home) = dirs::home_dir() {
+            global.push(home.join(".gitconfig").to_string_lossy().to_string());
+            global.push(
+                home.join(".config/git/config")
+                    .to_string_lossy()
+                    .to_string(),
+            );
+        }
+        if let Ok(xdg_config_home) = std::env::var("XDG_CONFIG_HOME") {
+            global.push(format!("{}/git/config", xdg_config_home));
+        }
+
+        Ok(StandardConfigFiles { local, global })
+    }
+
+    fn classify(&self, entry: ConfigEntry) -> IdentityEntry {
+        let included = match entry.scope.as_str() {
+            "local" | "worktree" => !self.local.contains(&entry.origin),
+            "global" => !self.global.contains(&entry.origin),
+            _ => false,
+        };
+        IdentityEntry {
+            scope: entry.scope,
+            origin: entry.origin,
+            value: entry.value,
+            included,
+        }
+    }
+}
@@ -0,0 +1,439 @@
+use crate::core::config::expand_path;
+use crate::core::{Config, Error, Profile};
+use crate::i18n::Language;
+use crate::t;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use toml_edit::{Document, TableLike};
+
+/// 검사 결과의 심각도
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// 지금은 동작하지만 확인이 필요한 상태
+    Warning,
+    /// 사용할 때 실패하는 상태
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// 설정 파일 안의 위치 (1부터 시작)
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// `--fix`로 자동 수정할 수 있는 작업
+#[derive(Clone, Debug)]
+enum Fix {
+    RemoveMapping(String),
+    RemoveRemoteMapping(String),
+}
+
+/// 검사에서 발견한 문제 하나
+#[derive(Serialize)]
+pub struct Issue {
+    pub severity: Severity,
+    /// 문제 종류별로 고정된 코드 (`dangling_mapping` 등)
+    pub code: &'static str,
+    pub message: String,
+    pub location: Option<Location>,
+    /// `--fix`로 고칠 수 있는지
+    pub fixable: bool,
+    /// `--fix`로 고쳤는지
+    pub fixed: bool,
+    #[serde(skip)]
+    fix: Option<Fix>,
+}
+
+/// `git-switcher config validate` 결과
+#[derive(Serialize)]
+pub struct ValidationReport {
+    pub path: String,
+    pub issues: Vec<Issue>,
+}
+
+impl ValidationReport {
+    /// 설정과 원본 파일 내용(위치 계산용)을 검사해 모든 문제를 수집
+    pub fn collect(config: &Config, path: &Path, content: &str) -> Self {
+        let mut validator = Validator {
+            config,
+            document: Document::parse(content).ok(),
+            content,
+            issues: Vec::new(),
+        };
+        validator.check_language();
+        validator.check_mappings();
+        validator.check_remote_mappings();
+        validator.check_profiles();
+
+        let mut issues = validator.issues;
+        issues.sort_by_key(|issue| issue.location.map(|location| location.line));
+        ValidationReport {
+            path: path.display().to_string(),
+            issues,
+        }
+    }
+
+    /// 자동으로 고칠 수 있는 문제를 설정에 반영하고 고친 개수를 반환
+    pub fn apply_fixes(&mut self, config: &mut Config) -> usize {
+        let mut fixed = 0;
+        for issue in &mut self.issues {
+            match &issue.fix {
+                Some(Fix::RemoveMapping(path)) => {
+                    config.remove_path_mapping(path);
+                }
+                Some(Fix::RemoveRemoteMapping(pattern)) => {
+                    config.remove_remote_mapping(pattern);
+                }
+                None => continue,
+            }
+            issue.fixed = true;
+            fixed += 1;
+        }
+        fixed
+    }
+
+    /// 남아 있는(고치지 않은) 문제 수
+    pub fn count(&self, severity: Severity) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == severity && !issue.fixed)
+            .count()
+    }
+
+    /// 고칠 수 있지만 아직 고치지 않은 문제 수
+    pub fn fixable(&self) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.fixable && !issue.fixed)
+            .count()
+    }
+
+    /// 오류가 남아 있으면 설정 오류와 같은 종료 코드 7
+    pub fn exit_code(&self) -> i32 {
+        if self.count(Severity::Error) > 0 {
+            7
+        } else {
+            0
+        }
+    }
+}
+
+struct Validator<'a> {
+    config: &'a Config,
+    /// 위치 정보를 가진 문서 (해석할 수 없으면 위치 없이 보고)
+    document: Option<Document<&'a str>>,
+    content: &'a str,
+    issues: Vec<Issue>,
+}
+
+impl Validator<'_> {
+    /// 알 수 없는 언어는 오류 없이 영어로 표시되므로 경고
+    fn check_language(&mut self) {
+        if let Some(language) = &self.config.language
+            && Language::from_str(language, true).is_err()
+        {
+            self.push(
+                Severity::Warning,
+                "unknown_language",
+                t!("validate.unknown_language", language),
+                self.locate(&["language"]),
+                None,
+            );
+        }
+    }
+
+    fn check_mappings(&mut self) {
+        // 펼친 경로가 같은 매핑은 어느 쪽이 적용될지 알기 어려우므로 먼저 나온 것과 비교
+        let mut seen: BTreeMap<String, (&String, &String)> = BTreeMap::new();
+
+        for (path, profile) in &self.config.path_mappings {
+            let location = self.locate(&["path_mappings", path]);
+
+            if !self.config.profiles.contains_key(profile) {
+                self.push(
+                    Severity::Error,
+                    "dangling_mapping",
+                    t!("validate.dangling_mapping", path, profile),
+                    location,
+                    self.mapping_fix(path),
+                );
+                continue;
+            }
+
+            let expanded = expand_path(path);
+            if !Path::new(&expanded).is_dir() {
+                self.push(
+                    Severity::Warning,
+                    "missing_directory",
+                    t!("validate.missing_directory", path),
+                    location,
+                    None,
+                );
+            }
+
+            let normalized = expanded.trim_end_matches('/').to_string();
+            match seen.get(&normalized) {
+                Some((first_path, first_profile)) => {
+                    // 같은 프로필이면 지워도 동작이 바뀌지 않으므로 자동 수정
+                    let fix = if *first_profile == profile {
+                        self.mapping_fix(path)
+                    } else {
+                        None
+                    };
+                    self.push(
+                        if fix.is_some() {
+                            Severity::Warning
+                        } else {
+                            Severity::Error
+                        },
+                        "duplicate_mapping",
+                        t!("validate.duplicate_mapping", path, first_path),
+                        location,
+                        fix,
+                    );
+                }
+                None => {
+                    seen.insert(normalized, (path, profile));
+                }
+            }
+        }
+    }
+
+    fn check_remote_mappings(&mut self) {
+        for (pattern, profile) in &self.config.remote_mappings {
+            if !self.config.profiles.contains_key(profile) {
+                let fix = (!self.config.is_system_value(&["remote_mappings", pattern]))
+                    .then(|| Fix::RemoveRemoteMapping(pattern.clone()));
+                self.push(
+                    Severity::Error,
+                    "dangling_mapping",
+                    t!("validate.dangling_mapping", pattern, profile),
+                    self.locate(&["remote_mappings", pattern]),
+                    fix,
+                );
+            }
+        }
+    }
+
+    fn check_profiles(&mut self) {
+        if let Some(defaults) = &self.config.defaults {
+            self.check_fields(&["defaults"], defaults);
+        }
+
+        for (name, profile) in &self.config.profiles {
+            let table = ["profiles", name.as_str()];
+            self.check_fields(&table, profile);
+
+            if let Some(parent) = &profile.extends
+                && !self.config.profiles.contains_key(parent)
+            {
+                self.push(
+                    Severity::Error,
+                    "missing_parent",
+                    t!("validate.missing_parent", name, parent),
+                    self.locate(&["profiles", name, "extends"]),
+                    None,
+                );
+            } else if let Err(Error::InheritanceCycle(chain)) = self.config.resolve_profile(name) {
+                self.push(
+                    Severity::Error,
+                    "inheritance_cycle",
+                    t!("validate.inheritance_cycle", name, chain),
+                    self.locate(&["profiles", name, "extends"]),
+                    None,
+                );
+            }
+        }
+    }
+
+    /// 프로필이나 `[defaults]`에 직접 적힌 값 검사 (상속받은 값은 정의된 곳에서 검사)
+    fn check_fields(&mut self, table: &[&str], profile: &Profile) {
+        let owner = table.join(".");
+        let field = |key: &'static str| [table, &[key]].concat();
+
+        if !profile.email.is_empty() && !is_valid_email(&profile.email) {
+            self.push(
+                Severity::Error,
+                "invalid_email",
+                t!("validate.invalid_email", owner, profile.email),
+                self.locate(&field("email")),
+                None,
+            );
+        }
+
+        if let Err(e) = profile.get_decrypted_pat() {
+            self.push(
+                Severity::Error,
+                "undecryptable_pat",
+                t!("validate.undecryptable_pat", owner, format!("{:#}", e)),
+                self.locate(&field("encrypted_pat")),
+                None,
+            );
+        }
+
+        if let Some(ssh_key) = &profile.ssh_key
+            && !Path::new(&expand_path(ssh_key)).exists()
+        {
+            self.push(
+                Severity::Warning,
+                "missing_ssh_key",
+                t!("validate.missing_ssh_key", owner, ssh_key),
+                self.locate(&field("ssh_key")),
+                None,
+            );
+        }
+    }
+
+    /// 매핑 제거 수정 (시스템 레이어의 매핑은 사용자 설정에서 지울 수 없으므로 없음)
+    fn mapping_fix(&self, path: &str) -> Option<Fix> {
+        (!self.config.is_system_value(&["path_mappings", path]))
+            .then(|| Fix::RemoveMapping(path.to_string()))
+    }
+
+    fn push(
+        &mut self,
+        severity: Severity,
+        code: &'static str,
+        message: String,
+        location: Option<Location>,
+        fix: Option<Fix>,
+    ) {
+        self.issues.push(Issue {
+            severity,
+            code,
+            message,
+            location,
+            fixable: fix.is_some(),
+            fixed: false,
+            fix,
+        });
+    }
+
+    /// 키 경로가 정의된 위치 (끝까지 찾지 못하면 찾은 데까지의 위치)
+    fn locate(&self, keys: &[&str]) -> Option<Location> {
+        let mut table: &dyn TableLike = self.document.as_ref()?.as_table();
+        let mut span = None;
+        for key in keys {
+            let Some((found_key, item)) = table.get_key_value(key) else {
+                break;
+            };
+            span = found_key.span().or_else(|| item.span()).or(span);
+            match item.as_table_like() {
+                Some(inner) => table = inner,
+                None => break,
+            }
+        }
+        span.map(|span| self.location(span.start))
+    }
+
+    fn location(&self, offset: usize) -> Location {
+        let before = &self.content[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+/// `local@domain.tld` 형태인지 간단히 확인
+fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !email.chars().any(char::is_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(content: &str) -> (Config, ValidationReport) {
+        let config: Config = toml::from_str(content).unwrap();
+        let report = ValidationReport::collect(&config, Path::new("config.toml"), content);
+        (config, report)
+    }
+
+    fn codes(report: &ValidationReport) -> Vec<(&'static str, Severity, usize)> {
+        report
+            .issues
+            .iter()
+            .map(|issue| {
+                let line = issue.location.map_or(0, |location| location.line);
+                (issue.code, issue.severity, line)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_config_has_no_issues() {
+        let content = format!(
+            "version = 1\nlanguage = \"en\"\n\n[profiles.work]\nname = \"Hong\"\nemail = \"hong@acme.com\"\n\n[path_mappings]\n{:?} = \"work\"\n",
+            std::env::temp_dir().display().to_string()
+        );
+        let (_, report) = validate(&content);
+        assert!(report.issues.is_empty());
+        assert_eq!(report.exit_code(), 0);
+    }
+
+    #[test]
+    fn reports_each_problem_with_its_line() {
+        let (mut config, mut report) = validate(
+            r#"version = 1
+language = "kr"
+
+[profiles.work]
+name = "Hong"
+email = "hong at acme.com"
+
+[path_mappings]
+"/nonexistent/git-switcher-test" = "gone"
+"#,
+        );
+        assert_eq!(
+            codes(&report),
+            vec![
+                ("unknown_language", Severity::Warning, 2),
+                ("invalid_email", Severity::Error, 6),
+                ("dangling_mapping", Severity::Error, 9),
+            ]
+        );
+        assert_eq!(report.exit_code(), 7);
+
+        // 없는 프로필을 가리키는 매핑만 자동으로 고칠 수 있음
+        assert_eq!(report.fixable(), 1);
+        assert_eq!(report.apply_fixes(&mut config), 1);
+        assert!(config.path_mappings.is_empty());
+        assert_eq!(report.count(Severity::Error), 1);
+    }
+
+    #[test]
+    fn email_format() {
+        assert!(is_valid_email("hong@acme.com"));
+        assert!(is_valid_email("hong+git@dev.acme.co.kr"));
+        assert!(!is_valid_email("hong@acme"));
+        assert!(!is_valid_email("@acme.com"));
+        assert!(!is_valid_email("hong@@acme.com"));
+        assert!(!is_valid_email("hong@.acme.com"));
+        assert!(!is_valid_email("hong @acme.com"));
+    }
+}
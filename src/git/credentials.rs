@@ -1,40 +1,58 @@
-use crate::core::{Profile, Result};
+use crate::core::{ActivityEvent, ActivityLog, Profile, Result};
 use crate::git::GitConfig;
+use crate::{report, t};
 
 pub struct CredentialManager;
 
+/// 일회용 크리덴셜 헬퍼가 사용자명과 PAT를 읽는 환경 변수
+pub const USERNAME_ENV: &str = "GIT_SWITCHER_USERNAME";
+pub const PAT_ENV: &str = "GIT_SWITCHER_PAT";
+
+/// 환경 변수의 사용자명/PAT만 돌려주고 저장이나 삭제는 하지 않는 일회용 크리덴셜 헬퍼
+///
+/// 저장된 다른 계정의 크리덴셜 대신 이 값만 쓰도록 앞에 빈 값을 두어 헬퍼 목록을 비움
+pub const ONE_SHOT_HELPERS: [&str; 2] = [
+    "",
+    "!f() { test \"$1\" = get && \
+     printf 'username=%s\\npassword=%s\\n' \
+     \"$GIT_SWITCHER_USERNAME\" \"$GIT_SWITCHER_PAT\"; }; f",
+];
+
 impl CredentialManager {
     /// PAT 크리덴셜 자동 설정
-    pub fn setup_pat_credentials(profile: &Profile) -> Result<()> {
+    pub fn setup_pat_credentials(profile_name: &str, profile: &Profile) -> Result<()> {
         if let Some(github_username) = &profile.github_username
-            && let Ok(Some(pat)) = profile.get_decrypted_pat()
+            && let Some(pat) = profile.get_decrypted_pat()?
         {
-            println!("  🔑 GitHub PAT 자동 설정 중...");
+            report!(Step(1), "{}", t!("credentials.pat_setup"));
 
             // PAT 유효성 검증
-            match GitConfig::test_github_pat(github_username, &pat) {
-                Ok(true) => {
-                    GitConfig::store_github_credentials(github_username, &pat)?;
-                }
+            let stored = match GitConfig::test_github_pat(github_username, &pat) {
+                Ok(true) => GitConfig::store_github_credentials(github_username, &pat),
                 Ok(false) => {
-                    println!("  ⚠️  PAT가 유효하지 않을 수 있습니다. 수동으로 확인해주세요.");
-                    GitConfig::store_github_credentials(github_username, &pat)?;
-                }
-                Err(_) => {
-                    // 검증 실패해도 저장은 시도
-                    GitConfig::store_github_credentials(github_username, &pat)?;
+                    report!(Warning(1), "{}", t!("credentials.pat_maybe_invalid"));
+                    GitConfig::store_github_credentials(github_username, &pat)
                 }
-            }
+                // 검증 실패해도 저장은 시도
+                Err(_) => GitConfig::store_github_credentials(github_username, &pat),
+            };
+            ActivityLog::record(
+                ActivityEvent::CredentialStore,
+                Some(profile_name),
+                Some(github_username),
+                stored.as_ref().err(),
+            );
+            stored?;
         }
         Ok(())
     }
 
     /// 기존 크리덴셜 삭제하여 계정 충돌 방지
-    pub fn clear_existing_credentials(profile: &Profile) -> Result<()> {
+    pub fn clear_existing_credentials(profile_name: &str, profile: &Profile) -> Result<()> {
         // GitHub 계정의 경우
         if let Some(github_username) = &profile.github_username {
             // 1. Git Credential Manager에서 GitHub 계정 삭제 시도
-            GitConfig::clear_github_credentials(github_username)?;
+            Self::clear_account(profile_name, github_username)?;
 
             // 2. 일반 크리덴셜 삭제도 시도 (Windows/Linux 호환성)
             let _ = GitConfig::erase_credentials_for_host("github.com", github_username);
@@ -43,17 +61,29 @@ impl CredentialManager {
         // URL에서 추출한 사용자명으로도 시도
         let remotes = GitConfig::get_remotes()?;
         for (_, url) in &remotes {
-            if let Some(username) = Self::extract_username_from_url(url) {
-                if username != profile.github_username.as_deref().unwrap_or("") {
-                    GitConfig::clear_github_credentials(&username)?;
-                    let _ = GitConfig::erase_credentials_for_host("github.com", &username);
-                }
+            if let Some(username) = Self::extract_username_from_url(url)
+                && username != profile.github_username.as_deref().unwrap_or("")
+            {
+                Self::clear_account(profile_name, &username)?;
+                let _ = GitConfig::erase_credentials_for_host("github.com", &username);
             }
         }
 
         Ok(())
     }
 
+    /// 계정의 크리덴셜을 삭제하고 활동 기록에 남김
+    fn clear_account(profile_name: &str, username: &str) -> Result<()> {
+        let cleared = GitConfig::clear_github_credentials(username);
+        ActivityLog::record(
+            ActivityEvent::CredentialErase,
+            Some(profile_name),
+            Some(username),
+            cleared.as_ref().err(),
+        );
+        cleared
+    }
+
     fn extract_username_from_url(url: &str) -> Option<String> {
         // URL에서 실제 사용자명 추출
         // 예: https://github.com/whoisdreamer/blog → whoisdreamer
@@ -2,4 +2,4 @@ pub mod credentials;
 pub mod operations;
 
 pub use credentials::CredentialManager;
-pub use operations::GitConfig;
+pub use operations::{CommitInfo, ConfigEntry, GitConfig};
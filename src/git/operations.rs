@@ -1,79 +1,438 @@
 use crate::core::{Error, Result};
+use crate::{report, t};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 pub struct GitConfig;
 
+thread_local! {
+    /// git 명령을 실행할 디렉토리 (없으면 프로세스의 현재 디렉토리)
+    static WORK_DIR: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+}
+
+/// `GitConfig::work_in`으로 바꾼 실행 디렉토리를 유지하는 값 (버리면 이전 디렉토리로 돌아감)
+pub struct WorkDir {
+    previous: Option<PathBuf>,
+}
+
+impl Drop for WorkDir {
+    fn drop(&mut self) {
+        WORK_DIR.set(self.previous.take());
+    }
+}
+
+/// GitHub `/user` 응답에서 사용하는 필드
+#[derive(Deserialize)]
+struct GithubUser {
+    login: String,
+}
+
+/// `git config --show-scope --show-origin`으로 조회한 설정 값 하나
+pub struct ConfigEntry {
+    /// local, global, system, worktree, command 중 하나
+    pub scope: String,
+    /// 값이 정의된 파일 (`include`로 포함된 파일이면 그 파일)
+    pub origin: String,
+    pub value: String,
+}
+
+/// 현재 저장소의 작업 트리 상태
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct WorktreeState {
+    /// 저장소의 작업 트리 수 (주 작업 트리 포함)
+    pub count: usize,
+    /// `git worktree add`로 만든 연결된 작업 트리 안인지
+    pub linked: bool,
+    /// `extensions.worktreeConfig`가 켜져 있어 작업 트리별 설정(`config.worktree`)에 쓰는지
+    pub per_worktree: bool,
+}
+
+/// `git log`으로 조회한 커밋 하나
+#[derive(Clone, Debug, Serialize)]
+pub struct CommitInfo {
+    pub commit: String,
+    #[serde(skip)]
+    pub parents: Vec<String>,
+    #[serde(skip)]
+    pub tree: String,
+    pub subject: String,
+    pub author_name: String,
+    pub author_email: String,
+    /// `<유닉스 시각> <시간대>` 형식 (다시 쓸 때 그대로 사용)
+    #[serde(skip)]
+    pub author_date: String,
+    pub committer_name: String,
+    pub committer_email: String,
+    #[serde(skip)]
+    pub committer_date: String,
+}
+
 impl GitConfig {
+    /// 이 스레드의 git 명령을 `path`에서 실행 (프로세스의 현재 디렉토리는 바꾸지 않음)
+    ///
+    /// 상대 경로는 지금의 실행 디렉토리 기준
+    pub fn work_in(path: impl AsRef<Path>) -> Result<WorkDir> {
+        let path = Path::new(&Self::get_current_directory()?).join(path);
+        Ok(WorkDir {
+            previous: WORK_DIR.replace(Some(path)),
+        })
+    }
+
+    fn git() -> Command {
+        let mut command = Command::new("git");
+        WORK_DIR.with_borrow(|dir| {
+            if let Some(dir) = dir {
+                command.current_dir(dir);
+            }
+        });
+        command
+    }
+
     pub fn is_git_repo() -> bool {
-        Command::new("git")
-            .args(&["rev-parse", "--git-dir"])
+        Self::git()
+            .args(["rev-parse", "--git-dir"])
             .output()
             .map(|output| output.status.success())
             .unwrap_or(false)
     }
 
     pub fn set_user_name(name: &str) -> Result<()> {
-        let status = Command::new("git")
-            .args(&["config", "--local", "user.name", name])
+        let scope = Self::repo_scope();
+        report!(Debug(1), "git config {} user.name {}", scope, name);
+        let status = Self::git()
+            .args(["config", scope, "user.name", name])
             .status()?;
 
         if !status.success() {
-            return Err(Error::Git("user.name 설정 실패".to_string()));
+            return Err(Error::Git(t!("git.set_failed", "user.name")));
         }
         Ok(())
     }
 
     pub fn set_user_email(email: &str) -> Result<()> {
-        let status = Command::new("git")
-            .args(&["config", "--local", "user.email", email])
+        let scope = Self::repo_scope();
+        report!(Debug(1), "git config {} user.email {}", scope, email);
+        let status = Self::git()
+            .args(["config", scope, "user.email", email])
             .status()?;
 
         if !status.success() {
-            return Err(Error::Git("user.email 설정 실패".to_string()));
+            return Err(Error::Git(t!("git.set_failed", "user.email")));
         }
         Ok(())
     }
 
     pub fn set_signing_key(key: &str) -> Result<()> {
-        let status = Command::new("git")
-            .args(&["config", "--local", "user.signingkey", key])
+        let scope = Self::repo_scope();
+        report!(Debug(1), "git config {} user.signingkey {}", scope, key);
+        let status = Self::git()
+            .args(["config", scope, "user.signingkey", key])
             .status()?;
 
         if !status.success() {
-            return Err(Error::Git("user.signingkey 설정 실패".to_string()));
+            return Err(Error::Git(t!("git.set_failed", "user.signingkey")));
         }
         Ok(())
     }
 
-    pub fn get_user_name() -> Result<String> {
-        let output = Command::new("git")
-            .args(&["config", "--local", "user.name"])
+    /// 전역 설정 값 조회 (없으면 None)
+    pub fn get_global_config(key: &str) -> Option<String> {
+        let output = Self::git()
+            .args(["config", "--global", "--get", key])
+            .output()
+            .ok()?;
+
+        if output.status.success() {
+            Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        } else {
+            None
+        }
+    }
+
+    /// 모든 범위(local/global/system 및 include)의 설정 값을 우선순위 오름차순으로 조회
+    ///
+    /// 마지막 항목이 실제로 적용되는 값
+    pub fn get_config_entries(key: &str) -> Result<Vec<ConfigEntry>> {
+        let output = Self::git()
+            .args([
+                "config",
+                "--show-scope",
+                "--show-origin",
+                "-z",
+                "--get-all",
+                key,
+            ])
+            .output()?;
+
+        match output.status.code() {
+            Some(0) => {}
+            // 종료 코드 1: 해당 키가 없음
+            Some(1) => return Ok(Vec::new()),
+            _ => return Err(Error::Git(t!("git.get_failed", key))),
+        }
+
+        // scope\0origin\0value\0 반복
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let fields: Vec<&str> = stdout.split('\0').collect();
+        Ok(fields
+            .chunks_exact(3)
+            .map(|chunk| ConfigEntry {
+                scope: chunk[0].to_string(),
+                origin: chunk[1]
+                    .strip_prefix("file:")
+                    .unwrap_or(chunk[1])
+                    .to_string(),
+                value: chunk[2].to_string(),
+            })
+            .collect())
+    }
+
+    /// 설정 파일 하나의 모든 항목을 파일에 적힌 순서대로 조회 (include는 따라가지 않음)
+    ///
+    /// 키는 git이 정규화한 형태 (섹션과 키 이름은 소문자, 하위 섹션은 원래 대소문자)
+    pub fn list_file_config(path: &Path) -> Result<Vec<(String, String)>> {
+        if !path.is_file() {
+            return Ok(Vec::new());
+        }
+        let output = Self::git()
+            .args(["config", "--file"])
+            .arg(path)
+            .args(["-z", "--list"])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(Error::Git(t!("git.read_file_failed", path.display())));
+        }
+
+        // key\nvalue\0 반복 (값이 없는 키는 key\0)
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .split('\0')
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| match entry.split_once('\n') {
+                Some((key, value)) => (key.to_string(), value.to_string()),
+                None => (entry.to_string(), String::new()),
+            })
+            .collect())
+    }
+
+    /// 현재 저장소의 로컬 설정 파일 경로
+    pub fn get_local_config_path() -> Result<String> {
+        let output = Self::git()
+            .args(["rev-parse", "--git-path", "config"])
             .output()?;
 
         if output.status.success() {
             Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
         } else {
-            Err(Error::Git("user.name을 가져올 수 없습니다".to_string()))
+            Err(Error::NotGitRepo)
         }
     }
 
-    pub fn get_user_email() -> Result<String> {
-        let output = Command::new("git")
-            .args(&["config", "--local", "user.email"])
+    /// 현재 작업 트리의 설정 파일 경로 (`extensions.worktreeConfig`일 때 쓰는 `config.worktree`)
+    pub fn get_worktree_config_path() -> Result<String> {
+        let output = Self::git()
+            .args(["rev-parse", "--git-path", "config.worktree"])
+            .output()?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        } else {
+            Err(Error::NotGitRepo)
+        }
+    }
+
+    /// 저장소 설정을 쓰는 범위 (`extensions.worktreeConfig`가 켜져 있으면 작업 트리별 설정)
+    ///
+    /// 켜져 있을 때 `--local`에 쓰면 `config.worktree`의 값에 가려질 수 있으므로 `--worktree`에 씀
+    fn repo_scope() -> &'static str {
+        if Self::worktree_config_enabled() {
+            "--worktree"
+        } else {
+            "--local"
+        }
+    }
+
+    fn worktree_config_enabled() -> bool {
+        Self::git()
+            .args(["config", "--bool", "--get", "extensions.worktreeConfig"])
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim() == "true")
+            .unwrap_or(false)
+    }
+
+    /// 작업 트리 수와 현재 위치가 연결된 작업 트리인지 확인
+    pub fn worktree_state() -> Result<WorktreeState> {
+        let output = Self::git()
+            .args(["worktree", "list", "--porcelain"])
+            .output()?;
+        if !output.status.success() {
+            return Err(Error::Git(t!("git.worktree_failed")));
+        }
+        let count = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| line.starts_with("worktree "))
+            .count();
+
+        // 연결된 작업 트리는 자기 git 디렉토리(.git/worktrees/<이름>)와 공통 디렉토리가 다름
+        let output = Self::git()
+            .args(["rev-parse", "--git-dir", "--git-common-dir"])
+            .output()?;
+        if !output.status.success() {
+            return Err(Error::NotGitRepo);
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let directories: Vec<PathBuf> = stdout
+            .lines()
+            .map(|line| {
+                Path::new(line)
+                    .canonicalize()
+                    .unwrap_or_else(|_| line.into())
+            })
+            .collect();
+        let linked =
+            matches!(directories.as_slice(), [git_dir, common_dir] if git_dir != common_dir);
+
+        Ok(WorktreeState {
+            count,
+            linked,
+            per_worktree: Self::worktree_config_enabled(),
+        })
+    }
+
+    /// `extensions.worktreeConfig`를 켜서 작업 트리마다 다른 신원을 쓸 수 있게 함
+    pub fn enable_worktree_config() -> Result<()> {
+        if Self::worktree_config_enabled() {
+            return Ok(());
+        }
+        report!(
+            Debug(1),
+            "git config --local extensions.worktreeConfig true"
+        );
+        let status = Self::git()
+            .args(["config", "--local", "extensions.worktreeConfig", "true"])
+            .status()?;
+
+        if !status.success() {
+            return Err(Error::Git(t!(
+                "git.set_failed",
+                "extensions.worktreeConfig"
+            )));
+        }
+        Ok(())
+    }
+
+    /// 초기화된 하위 모듈의 절대 경로 (중첩된 하위 모듈 포함, 상위 모듈이 먼저)
+    pub fn list_submodules() -> Result<Vec<String>> {
+        let output = Self::git()
+            .args(["submodule", "foreach", "--quiet", "--recursive", "pwd"])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(Error::Git(t!("git.submodules_failed")));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|line| line.to_string())
+            .collect())
+    }
+
+    /// 현재 저장소의 작업 트리 최상위 디렉토리
+    pub fn get_repo_root() -> Result<String> {
+        let output = Self::git()
+            .args(["rev-parse", "--show-toplevel"])
             .output()?;
 
         if output.status.success() {
             Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
         } else {
-            Err(Error::Git("user.email을 가져올 수 없습니다".to_string()))
+            Err(Error::NotGitRepo)
         }
     }
 
+    /// 로컬 설정 키의 모든 값 조회 (키가 없으면 빈 목록)
+    pub fn get_local_config_all(key: &str) -> Result<Vec<String>> {
+        let output = Self::git()
+            .args(["config", Self::repo_scope(), "--get-all", key])
+            .output()?;
+
+        match output.status.code() {
+            Some(0) => Ok(String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .map(|line| line.to_string())
+                .collect()),
+            // 종료 코드 1: 해당 키가 없음
+            Some(1) => Ok(Vec::new()),
+            _ => Err(Error::Git(t!("git.get_failed", key))),
+        }
+    }
+
+    /// 로컬 설정 키를 주어진 값들로 교체 (다중 값 키 지원)
+    pub fn set_local_config_all(key: &str, values: &[String]) -> Result<()> {
+        Self::unset_local_config(key)?;
+
+        let scope = Self::repo_scope();
+        for value in values {
+            report!(Debug(1), "git config {} --add {} {}", scope, key, value);
+            let status = Self::git()
+                .args(["config", scope, "--add", key, value])
+                .status()?;
+
+            if !status.success() {
+                return Err(Error::Git(t!("git.set_failed", key)));
+            }
+        }
+        Ok(())
+    }
+
+    /// 로컬 설정 키의 모든 값 제거 (키가 없어도 성공)
+    pub fn unset_local_config(key: &str) -> Result<()> {
+        let scope = Self::repo_scope();
+        report!(Debug(1), "git config {} --unset-all {}", scope, key);
+        let status = Self::git()
+            .args(["config", scope, "--unset-all", key])
+            .status()?;
+
+        // 종료 코드 5: 해당 키가 없음
+        match status.code() {
+            Some(0) | Some(5) => Ok(()),
+            _ => Err(Error::Git(t!("git.unset_failed", key))),
+        }
+    }
+
+    pub fn get_user_name() -> Result<String> {
+        Self::get_repo_config("user.name")
+            .ok_or_else(|| Error::Git(t!("git.get_failed", "user.name")))
+    }
+
+    pub fn get_user_email() -> Result<String> {
+        Self::get_repo_config("user.email")
+            .ok_or_else(|| Error::Git(t!("git.get_failed", "user.email")))
+    }
+
+    /// 저장소 설정 값 (작업 트리별 설정에 없으면 작업 트리들이 공유하는 로컬 설정 값)
+    fn get_repo_config(key: &str) -> Option<String> {
+        let scopes: &[&str] = if Self::worktree_config_enabled() {
+            &["--worktree", "--local"]
+        } else {
+            &["--local"]
+        };
+        scopes.iter().find_map(|scope| {
+            let output = Self::git().args(["config", scope, key]).output().ok()?;
+            output
+                .status
+                .success()
+                .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        })
+    }
+
     pub fn get_remotes() -> Result<Vec<(String, String)>> {
-        let output = Command::new("git").args(&["remote", "-v"]).output()?;
+        let output = Self::git().args(["remote", "-v"]).output()?;
 
         if !output.status.success() {
-            return Err(Error::Git("리모트 정보를 가져올 수 없습니다".to_string()));
+            return Err(Error::Git(t!("git.remotes_failed")));
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout);
@@ -90,31 +449,241 @@ impl GitConfig {
     }
 
     pub fn set_remote_url(remote_name: &str, url: &str) -> Result<()> {
-        let status = Command::new("git")
-            .args(&["remote", "set-url", remote_name, url])
+        report!(Debug(1), "git remote set-url {} {}", remote_name, url);
+        let status = Self::git()
+            .args(["remote", "set-url", remote_name, url])
             .status()?;
 
         if !status.success() {
-            return Err(Error::Git(format!(
-                "리모트 '{}' URL 설정 실패",
-                remote_name
+            return Err(Error::Git(t!("git.set_remote_url_failed", remote_name)));
+        }
+        Ok(())
+    }
+
+    /// `git clone` 실행 (`options`는 `clone` 앞에 붙는 `-c` 설정, `clone_options`는 새 저장소에 남는 설정)
+    ///
+    /// 비밀 값은 명령줄에 드러나지 않도록 `envs`로만 전달
+    pub fn clone_repository(
+        url: &str,
+        directory: &str,
+        options: &[String],
+        clone_options: &[String],
+        envs: &[(&str, String)],
+    ) -> Result<()> {
+        let mut args: Vec<String> = Vec::new();
+        for option in options {
+            args.extend(["-c".to_string(), option.clone()]);
+        }
+        args.push("clone".to_string());
+        for option in clone_options {
+            args.extend(["--config".to_string(), option.clone()]);
+        }
+        args.extend(["--".to_string(), url.to_string(), directory.to_string()]);
+
+        report!(Debug(1), "git clone {} {}", url, directory);
+        let status = Self::git()
+            .args(&args)
+            .envs(envs.iter().map(|(key, value)| (key, value)))
+            .status()?;
+
+        if !status.success() {
+            return Err(Error::Git(t!("git.clone_failed", url)));
+        }
+        Ok(())
+    }
+
+    /// `git log <revisions>`의 커밋 목록 (`revisions`는 범위와 `--reverse` 등의 옵션)
+    pub fn list_commits(revisions: &[&str]) -> Result<Vec<CommitInfo>> {
+        const FORMAT: &str =
+            "--format=%H%x00%P%x00%T%x00%s%x00%an%x00%ae%x00%ad%x00%cn%x00%ce%x00%cd%x1e";
+        let output = Self::git()
+            .args(["log", "--date=raw", FORMAT])
+            .args(revisions)
+            .arg("--")
+            .output()?;
+
+        if !output.status.success() {
+            return Err(Error::Git(t!(
+                "git.log_failed",
+                revisions.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
             )));
         }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .split('\x1e')
+            .filter_map(|record| {
+                let fields: Vec<&str> = record.trim_start_matches('\n').split('\0').collect();
+                let [
+                    commit,
+                    parents,
+                    tree,
+                    subject,
+                    author_name,
+                    author_email,
+                    author_date,
+                    committer_name,
+                    committer_email,
+                    committer_date,
+                ] = fields.as_slice()
+                else {
+                    return None;
+                };
+                Some(CommitInfo {
+                    commit: commit.to_string(),
+                    parents: parents.split_whitespace().map(str::to_string).collect(),
+                    tree: tree.to_string(),
+                    subject: subject.to_string(),
+                    author_name: author_name.to_string(),
+                    author_email: author_email.to_string(),
+                    author_date: author_date.to_string(),
+                    committer_name: committer_name.to_string(),
+                    committer_email: committer_email.to_string(),
+                    committer_date: committer_date.to_string(),
+                })
+            })
+            .collect())
+    }
+
+    /// 원격 추적 브랜치 중 하나에서 도달할 수 있는(이미 push한) 커밋인지
+    pub fn is_pushed(commit: &str) -> Result<bool> {
+        let output = Self::git()
+            .args([
+                "for-each-ref",
+                "--count=1",
+                "--format=%(refname)",
+                "--contains",
+                commit,
+                "refs/remotes/",
+            ])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(Error::Git(t!("git.get_failed", commit)));
+        }
+        Ok(!output.stdout.trim_ascii().is_empty())
+    }
+
+    /// 리비전을 커밋 ID로 변환
+    pub fn rev_parse(revision: &str) -> Result<String> {
+        let output = Self::git()
+            .args([
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("{}^{{commit}}", revision),
+            ])
+            .output()?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        } else {
+            Err(Error::Git(t!("git.unknown_revision", revision)))
+        }
+    }
+
+    /// 커밋의 원본 내용에서 메시지와 서명 여부 (`(메시지, 서명됨)`)
+    pub fn read_commit_message(commit: &str) -> Result<(String, bool)> {
+        let output = Self::git().args(["cat-file", "commit", commit]).output()?;
+
+        if !output.status.success() {
+            return Err(Error::Git(t!("git.get_failed", commit)));
+        }
+        let raw = String::from_utf8_lossy(&output.stdout);
+        let (headers, message) = raw.split_once("\n\n").unwrap_or((&raw, ""));
+        let signed = headers.lines().any(|line| line.starts_with("gpgsig"));
+        Ok((message.to_string(), signed))
+    }
+
+    /// 트리와 부모로 새 커밋을 만들고 ID 반환 (작성자/커미터와 날짜는 `envs`의 `GIT_AUTHOR_*` 등으로 지정)
+    pub fn commit_tree(
+        tree: &str,
+        parents: &[String],
+        message: &str,
+        envs: &[(&str, &str)],
+        signing_key: Option<&str>,
+    ) -> Result<String> {
+        use std::io::Write;
+
+        let mut command = Self::git();
+        command.args(["commit-tree", tree]);
+        for parent in parents {
+            command.args(["-p", parent]);
+        }
+        match signing_key {
+            Some(key) => command.arg(format!("-S{}", key)),
+            None => command.arg("--no-gpg-sign"),
+        };
+        let mut child = command
+            .envs(envs.iter().copied())
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .spawn()?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(message.as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(Error::Git(t!("git.commit_tree_failed", tree)));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// 참조를 `old`에서 `new`로 옮김 (그 사이 참조가 바뀌었으면 실패)
+    pub fn update_ref(reference: &str, new: &str, old: Option<&str>, reason: &str) -> Result<()> {
+        report!(Debug(1), "git update-ref {} {}", reference, new);
+        let mut command = Self::git();
+        command.args(["update-ref", "-m", reason, reference, new]);
+        if let Some(old) = old {
+            command.arg(old);
+        }
+
+        if !command.status()?.success() {
+            return Err(Error::Git(t!("git.update_ref_failed", reference)));
+        }
         Ok(())
     }
 
+    /// rebase, merge, cherry-pick 등이 진행 중인지
+    pub fn operation_in_progress() -> Result<bool> {
+        for path in [
+            "rebase-merge",
+            "rebase-apply",
+            "MERGE_HEAD",
+            "CHERRY_PICK_HEAD",
+            "REVERT_HEAD",
+        ] {
+            let output = Self::git()
+                .args(["rev-parse", "--git-path", path])
+                .output()?;
+            if !output.status.success() {
+                return Err(Error::NotGitRepo);
+            }
+            if Path::new(String::from_utf8_lossy(&output.stdout).trim()).exists() {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// 현재 실행 디렉토리 (`work_in`으로 바꿨으면 그 디렉토리)
     pub fn get_current_directory() -> Result<String> {
+        if let Some(dir) = WORK_DIR.with_borrow(Clone::clone) {
+            return Ok(dir.to_string_lossy().to_string());
+        }
         std::env::current_dir()
             .map(|path| path.to_string_lossy().to_string())
-            .map_err(|e| Error::Io(e))
+            .map_err(Error::Io)
     }
 
     /// Git Credential Manager에서 GitHub 계정 목록 조회
     pub fn get_cached_github_accounts() -> Result<Vec<String>> {
         // Windows에서만 credential-manager 사용
         if cfg!(windows) {
-            let output = Command::new("git")
-                .args(&["credential-manager", "github", "list"])
+            let output = Self::git()
+                .args(["credential-manager", "github", "list"])
                 .output()?;
 
             if output.status.success() {
@@ -137,14 +706,18 @@ impl GitConfig {
     /// Git Credential Manager에서 특정 GitHub 계정 삭제
     pub fn clear_github_credentials(username: &str) -> Result<()> {
         if cfg!(windows) {
-            let status = Command::new("git")
-                .args(&["credential-manager", "github", "logout", username])
+            let status = Self::git()
+                .args(["credential-manager", "github", "logout", username])
                 .status()?;
 
             if status.success() {
-                println!("  🔧 캐시된 크리덴셜 삭제: {}", username);
+                report!(
+                    Success(1),
+                    "{}",
+                    t!("git.cached_credentials_cleared", username)
+                );
             } else {
-                println!("  💡 크리덴셜이 캐시되지 않았음: {}", username);
+                report!(Hint(1), "{}", t!("git.credentials_not_cached", username));
             }
         } else {
             // Linux/macOS에서는 credential erase 사용
@@ -154,67 +727,101 @@ impl GitConfig {
         Ok(())
     }
 
+    /// 해당 GitHub 계정의 크리덴셜이 저장되어 있는지 git의 크리덴셜 헬퍼에 물어 확인
+    ///
+    /// store, osxkeychain, manager, libsecret 등 설정된 헬퍼를 모두 같은 방식으로 확인하며,
+    /// 저장된 값이 없을 때 입력을 기다리지 않도록 터미널과 askpass, GCM 프롬프트를 끔
+    pub fn has_stored_github_credentials(username: &str) -> bool {
+        use std::io::Write;
+
+        let Ok(mut child) = Self::git()
+            .args(["credential", "fill"])
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .env("GIT_ASKPASS", "")
+            .env("GCM_INTERACTIVE", "never")
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+        else {
+            return false;
+        };
+
+        if let Some(stdin) = child.stdin.as_mut() {
+            let input = format!("protocol=https\nhost=github.com\nusername={}\n\n", username);
+            if stdin.write_all(input.as_bytes()).is_err() {
+                return false;
+            }
+        }
+
+        child.wait_with_output().is_ok_and(|output| {
+            output.status.success()
+                && String::from_utf8_lossy(&output.stdout).lines().any(|line| {
+                    line.strip_prefix("password=")
+                        .is_some_and(|pat| !pat.is_empty())
+                })
+        })
+    }
+
     /// 모든 GitHub 계정의 크리덴셜 삭제
     pub fn clear_all_github_credentials() -> Result<()> {
         if cfg!(windows) {
             let accounts = Self::get_cached_github_accounts()?;
 
             if accounts.is_empty() {
-                println!("  💡 캐시된 GitHub 계정이 없습니다");
+                report!(Hint(1), "{}", t!("git.no_cached_accounts"));
             } else {
-                println!("  🔧 캐시된 GitHub 계정들을 삭제합니다: {:?}", accounts);
+                report!(
+                    Step(1),
+                    "{}",
+                    t!("git.clearing_cached_accounts", accounts.join(", "))
+                );
                 for account in accounts {
                     Self::clear_github_credentials(&account)?;
                 }
             }
         } else {
-            println!("  💡 Linux/macOS에서는 개별 계정 삭제만 지원됩니다");
+            report!(Hint(1), "{}", t!("git.clear_all_unsupported"));
         }
 
         Ok(())
     }
 
     /// Linux/macOS용 크리덴셜 삭제
+    ///
+    /// 저장된 크리덴셜이 없는 것은 실패가 아니며, git 명령을 실행하지 못한 경우만 오류
     fn erase_credentials_for_host_linux(host: &str, username: &str) -> Result<()> {
         use std::io::Write;
 
-        let child = Command::new("git")
-            .args(&["credential", "erase"])
+        report!(
+            Debug(1),
+            "git credential erase (host={}, username={})",
+            host,
+            username
+        );
+        let account = format!("{}@{}", username, host);
+        let mut child = Self::git()
+            .args(["credential", "erase"])
             .stdin(std::process::Stdio::piped())
             .stdout(std::process::Stdio::piped())
             .stderr(std::process::Stdio::piped())
-            .spawn();
-
-        match child {
-            Ok(mut child) => {
-                if let Some(stdin) = child.stdin.as_mut() {
-                    let input = format!("protocol=https\nhost={}\nusername={}\n", host, username);
-
-                    if let Err(_) = stdin.write_all(input.as_bytes()) {
-                        println!("  💡 크리덴셜 삭제 입력 실패: {}@{}", username, host);
-                        return Ok(());
-                    }
-                }
+            .spawn()
+            .map_err(|e| Error::CredentialErase(account.clone(), e))?;
+
+        if let Some(stdin) = child.stdin.as_mut() {
+            let input = format!("protocol=https\nhost={}\nusername={}\n", host, username);
+            stdin
+                .write_all(input.as_bytes())
+                .map_err(|e| Error::CredentialErase(account.clone(), e))?;
+        }
 
-                match child.wait() {
-                    Ok(status) => {
-                        if status.success() {
-                            println!("  🔧 크리덴셜 삭제 완료: {}@{}", username, host);
-                        } else {
-                            println!("  💡 크리덴셜이 저장되지 않았음: {}@{}", username, host);
-                        }
-                    }
-                    Err(_) => {
-                        println!("  💡 크리덴셜 삭제 명령 실패: {}@{}", username, host);
-                    }
-                }
-            }
-            Err(_) => {
-                println!(
-                    "  💡 git credential erase 명령 실행 실패: {}@{}",
-                    username, host
-                );
-            }
+        let status = child
+            .wait()
+            .map_err(|e| Error::CredentialErase(account.clone(), e))?;
+        if status.success() {
+            report!(Success(1), "{}", t!("git.erase_done", username, host));
+        } else {
+            report!(Hint(1), "{}", t!("git.erase_not_stored", username, host));
         }
 
         Ok(())
@@ -240,55 +847,37 @@ impl GitConfig {
     fn store_credentials_windows(username: &str, pat: &str) -> Result<()> {
         use std::io::Write;
 
-        let child = Command::new("git")
-            .args(&["credential", "store"])
+        report!(
+            Debug(1),
+            "git credential store (host=github.com, username={})",
+            username
+        );
+        let store_error = |e| Error::CredentialStore(username.to_string(), e);
+        let mut child = Self::git()
+            .args(["credential", "store"])
             .stdin(std::process::Stdio::piped())
             .stdout(std::process::Stdio::piped())
             .stderr(std::process::Stdio::piped())
-            .spawn();
-
-        match child {
-            Ok(mut child) => {
-                if let Some(stdin) = child.stdin.as_mut() {
-                    let input = format!(
-                        "protocol=https\nhost=github.com\nusername={}\npassword={}\n",
-                        username, pat
-                    );
-
-                    if let Err(e) = stdin.write_all(input.as_bytes()) {
-                        println!("  ⚠️  GitHub 크리덴셜 입력 실패: {} ({})", username, e);
-                        return Ok(());
-                    }
-                }
+            .spawn()
+            .map_err(store_error)?;
+
+        if let Some(stdin) = child.stdin.as_mut() {
+            let input = format!(
+                "protocol=https\nhost=github.com\nusername={}\npassword={}\n",
+                username, pat
+            );
+            stdin.write_all(input.as_bytes()).map_err(store_error)?;
+        }
 
-                match child.wait() {
-                    Ok(status) => {
-                        if status.success() {
-                            println!("  🔑 GitHub 크리덴셜 저장 완료: {}", username);
-                        } else {
-                            println!(
-                                "  ⚠️  GitHub 크리덴셜 저장 실패: {} (exit code: {:?})",
-                                username,
-                                status.code()
-                            );
-                        }
-                    }
-                    Err(e) => {
-                        println!(
-                            "  ⚠️  GitHub 크리덴셜 저장 프로세스 대기 실패: {} ({})",
-                            username, e
-                        );
-                    }
-                }
-            }
-            Err(e) => {
-                println!(
-                    "  ⚠️  git credential store 명령 실행 실패: {} ({})",
-                    username, e
-                );
-            }
+        let status = child.wait().map_err(store_error)?;
+        if !status.success() {
+            return Err(store_error(std::io::Error::other(format!(
+                "git credential store: {}",
+                status
+            ))));
         }
 
+        report!(Success(1), "{}", t!("git.store_done", username));
         Ok(())
     }
 
@@ -297,31 +886,38 @@ impl GitConfig {
         use std::fs::OpenOptions;
         use std::io::{BufRead, BufReader, Write};
 
+        let store_error = |e| Error::CredentialStore(username.to_string(), e);
+
         // credential.helper를 store로 설정
-        let _ = Command::new("git")
-            .args(&["config", "--global", "credential.helper", "store"])
-            .status();
+        report!(Debug(1), "git config --global credential.helper store");
+        let status = Self::git()
+            .args(["config", "--global", "credential.helper", "store"])
+            .status()
+            .map_err(store_error)?;
+        if !status.success() {
+            return Err(store_error(std::io::Error::other(format!(
+                "git config --global credential.helper store: {}",
+                status
+            ))));
+        }
 
         // 홈 디렉토리의 .git-credentials 파일 경로
-        let home_dir = dirs::home_dir()
-            .ok_or_else(|| Error::Other("홈 디렉토리를 찾을 수 없습니다".to_string()))?;
+        let home_dir = dirs::home_dir().ok_or(Error::HomeNotFound)?;
         let credentials_file = home_dir.join(".git-credentials");
 
         // 기존 파일에서 동일한 host/username 항목 제거
         let mut existing_lines = Vec::new();
         if credentials_file.exists() {
-            if let Ok(file) = std::fs::File::open(&credentials_file) {
-                let reader = BufReader::new(file);
-                for line in reader.lines() {
-                    if let Ok(line) = line {
-                        let line = line.trim();
-                        if !line.is_empty()
-                            && !line.contains(&format!("://{}@github.com", username))
-                            && !line.contains("github.com")
-                        {
-                            existing_lines.push(line.to_string());
-                        }
-                    }
+            let file = std::fs::File::open(&credentials_file).map_err(store_error)?;
+            let reader = BufReader::new(file);
+            for line in reader.lines() {
+                let line = line.map_err(store_error)?;
+                let line = line.trim();
+                if !line.is_empty()
+                    && !line.contains(&format!("://{}@github.com", username))
+                    && !line.contains("github.com")
+                {
+                    existing_lines.push(line.to_string());
                 }
             }
         }
@@ -331,38 +927,30 @@ impl GitConfig {
         existing_lines.push(new_entry);
 
         // 파일에 쓰기
-        match OpenOptions::new()
+        let mut file = OpenOptions::new()
             .create(true)
             .write(true)
             .truncate(true)
             .open(&credentials_file)
-        {
-            Ok(mut file) => {
-                for line in existing_lines {
-                    if let Err(e) = writeln!(file, "{}", line) {
-                        println!("  ⚠️  크리덴셜 파일 쓰기 실패: {} ({})", username, e);
-                        return Ok(());
-                    }
-                }
-
-                // 파일 권한을 600으로 설정 (보안)
-                #[cfg(unix)]
-                {
-                    use std::os::unix::fs::PermissionsExt;
-                    let _ = std::fs::set_permissions(
-                        &credentials_file,
-                        std::fs::Permissions::from_mode(0o600),
-                    );
-                }
+            .map_err(store_error)?;
+        for line in existing_lines {
+            writeln!(file, "{}", line).map_err(store_error)?;
+        }
 
-                println!("  🔑 GitHub 크리덴셜 저장 완료: {}", username);
-                println!("  📁 저장 위치: {}", credentials_file.display());
-            }
-            Err(e) => {
-                println!("  ⚠️  크리덴셜 파일 생성 실패: {} ({})", username, e);
-            }
+        // 파일 권한을 600으로 설정 (보안)
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&credentials_file, std::fs::Permissions::from_mode(0o600))
+                .map_err(store_error)?;
         }
 
+        report!(Success(1), "{}", t!("git.store_done", username));
+        report!(
+            Info(1),
+            "{}",
+            t!("git.credentials_location", credentials_file.display())
+        );
         Ok(())
     }
 
@@ -370,7 +958,7 @@ impl GitConfig {
     pub fn test_github_pat(_username: &str, pat: &str) -> Result<bool> {
         // GitHub API를 통해 토큰 유효성 검증
         let output = Command::new("curl")
-            .args(&[
+            .args([
                 "-s",
                 "-o",
                 "/dev/null",
@@ -395,4 +983,30 @@ impl GitConfig {
             }
         }
     }
+
+    /// GitHub API를 통해 PAT에서 사용자명 추출
+    pub fn detect_github_username(pat: &str) -> Result<String> {
+        let output = Command::new("curl")
+            .args([
+                "-s",
+                "-H",
+                &format!("Authorization: token {}", pat),
+                "-H",
+                "User-Agent: git-switcher",
+                "https://api.github.com/user",
+            ])
+            .output();
+
+        match output {
+            Ok(result) => {
+                if result.status.success()
+                    && let Ok(user) = serde_json::from_slice::<GithubUser>(&result.stdout)
+                {
+                    return Ok(user.login);
+                }
+                Err(Error::Network(t!("git.github_api_parse_failed"), None))
+            }
+            Err(e) => Err(Error::Network(t!("git.curl_unavailable"), Some(e))),
+        }
+    }
 }
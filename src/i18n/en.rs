@@ -0,0 +1,959 @@
+/// 영어 메시지 카탈로그
+pub const MESSAGES: &[(&str, &str)] = &[
+    ("error.io", "I/O error"),
+    ("error.config_parse", "Failed to parse config file"),
+    ("error.config_serialize", "Failed to serialize TOML"),
+    ("error.json_serialize", "Failed to serialize JSON"),
+    ("error.git", "Git error: {}"),
+    (
+        "error.not_git_repo",
+        "The current directory is not a Git repository",
+    ),
+    ("error.profile_not_found", "Profile '{}' not found"),
+    ("error.profile_exists", "Profile '{}' already exists"),
+    ("error.inheritance_cycle", "Profile inheritance cycle: {}"),
+    (
+        "error.incomplete_profile",
+        "Profile '{}' has no '{}' value (not set via extends or [defaults] either)",
+    ),
+    (
+        "error.config_not_found",
+        "Config file not found. Run 'git-switcher init' to create one",
+    ),
+    (
+        "error.no_mapped_profile",
+        "No profile is mapped to the current path: {}",
+    ),
+    ("profile.decrypt_failed", "(decryption failed)"),
+    ("status.none", "(none)"),
+    ("status.expected_local", "expected: {}, local value: {}"),
+    ("status.expected_actual", "expected: {}, actual: {}"),
+    (
+        "status.credentials_stored",
+        "credentials stored for {}@github.com",
+    ),
+    (
+        "status.credentials_missing",
+        "no credentials stored for {}@github.com",
+    ),
+    ("status.ssh_key_exists", "SSH key found: {}"),
+    ("status.ssh_key_missing", "SSH key file is missing: {}"),
+    ("status.no_ssh_remotes", "no remotes use SSH"),
+    ("status.ssh_remotes", "SSH remotes: {}"),
+    ("crypto.decode_failed", "Failed to decode token: {}"),
+    ("crypto.invalid_format", "Invalid encrypted data format"),
+    ("crypto.utf8_failed", "Token is not valid UTF-8: {}"),
+    (
+        "ssh.example_header",
+        "Example settings to add to ~/.ssh/config",
+    ),
+    ("ssh.example_host", "{} profile settings for {}"),
+    ("credentials.pat_setup", "Configuring GitHub PAT..."),
+    (
+        "credentials.pat_maybe_invalid",
+        "The PAT may be invalid. Please check it manually.",
+    ),
+    ("git.set_failed", "Failed to set {}"),
+    ("git.get_failed", "Could not read {}"),
+    ("git.unset_failed", "Failed to unset {}"),
+    ("git.remotes_failed", "Could not read remotes"),
+    (
+        "git.set_remote_url_failed",
+        "Failed to set URL of remote '{}'",
+    ),
+    (
+        "git.cached_credentials_cleared",
+        "Cleared cached credentials: {}",
+    ),
+    ("git.credentials_not_cached", "No cached credentials: {}"),
+    ("git.no_cached_accounts", "No cached GitHub accounts"),
+    (
+        "git.clearing_cached_accounts",
+        "Clearing cached GitHub accounts: {}",
+    ),
+    (
+        "git.clear_all_unsupported",
+        "Only per-account removal is supported on Linux/macOS",
+    ),
+    ("git.erase_done", "Credentials erased: {}@{}"),
+    ("git.erase_not_stored", "No stored credentials: {}@{}"),
+    ("git.store_done", "GitHub credentials stored: {}"),
+    ("git.credentials_location", "Stored in: {}"),
+    (
+        "git.github_api_parse_failed",
+        "Failed to parse GitHub API response",
+    ),
+    (
+        "apply.clearing_credentials",
+        "Clearing existing credentials...",
+    ),
+    ("apply.applied", "Profile applied."),
+    ("field.name", "Name: {}"),
+    ("field.email", "Email: {}"),
+    ("field.gpg_key", "GPG key: {}"),
+    ("apply.extra_git_config", "Extra Git config:"),
+    ("apply.ssh_enabled", "SSH: enabled"),
+    (
+        "apply.pat_configured",
+        "The PAT was configured automatically. You can push right away!",
+    ),
+    (
+        "apply.pat_required",
+        "You will be asked for a PAT on the next push",
+    ),
+    ("show.current_profile", "Current Git profile:"),
+    (
+        "show.no_profile",
+        "No Git profile is configured in this repository.",
+    ),
+    ("status.repository", "Repository: {}"),
+    (
+        "status.applied_settings",
+        "Effective Git config (highest priority first):",
+    ),
+    ("status.not_set", "(not set)"),
+    (
+        "status.current_profile",
+        "Profile matching current config: {}",
+    ),
+    ("status.no_matching_profile", "(no matching profile)"),
+    (
+        "status.expected_profile",
+        "Profile expected by path rules: {}",
+    ),
+    ("status.no_mapping", "(no mapping)"),
+    ("status.checks", "Checks:"),
+    ("status.result", "Result: {}"),
+    ("auto.detected", "Detected profile: {}"),
+    ("auto.path", "Path: {}"),
+    ("auto.available_mappings", "Available path mappings:"),
+    ("auto.no_mappings", "(no mapped paths)"),
+    ("auto.how_to_map", "To add a path mapping:"),
+    ("auto.map_usage", "git-switcher map <path> <profile>"),
+    (
+        "auto.map_example",
+        "e.g. git-switcher map ~/workspace/company company",
+    ),
+    (
+        "auto.no_repos",
+        "No Git repositories found in mapped paths.",
+    ),
+    ("auto.found_repos", "Git repositories found:"),
+    ("auto.processing", "Processing: {}"),
+    ("auto.chdir_failed", "Failed to change directory: {}"),
+    ("auto.applied", "Applied profile {}"),
+    ("auto.apply_failed", "Failed to apply profile: {}"),
+    ("auto.load_failed", "Failed to load profile: {}"),
+    ("picker.applied", "currently applied"),
+    ("picker.mapped", "path mapping"),
+    ("picker.prompt", "Profile to apply (type to search)"),
+    ("picker.available", "Available profiles:"),
+    ("picker.numbered_prompt", "Enter a number or name"),
+    ("wizard.profile_name", "Profile name"),
+    ("wizard.profile_name_required", "Enter a profile name"),
+    ("wizard.user_name", "User name"),
+    ("wizard.email", "Email address"),
+    ("wizard.email_invalid", "Enter a valid email address"),
+    ("wizard.github_username", "GitHub username (Enter to skip)"),
+    ("wizard.github_pat", "GitHub PAT (Enter to skip)"),
+    (
+        "pat.format_warning",
+        "Warning: the token may not be a valid GitHub PAT.",
+    ),
+    ("wizard.verify_pat", "Verify the token with the GitHub API?"),
+    ("pat.valid", "The token is valid."),
+    ("pat.username_detected", "Detected GitHub username: {}"),
+    (
+        "wizard.pat_unverified",
+        "Could not verify the token. Please check it later.",
+    ),
+    ("pat.stored", "GitHub PAT encrypted and stored: {}"),
+    (
+        "wizard.map_current_dir",
+        "Map the current directory to this profile? ({})",
+    ),
+    (
+        "wizard.ssh_key_path_optional",
+        "SSH key path (Enter to skip)",
+    ),
+    ("wizard.none", "(none)"),
+    ("wizard.enter_manually", "Enter manually"),
+    ("wizard.ssh_key", "SSH key"),
+    ("wizard.ssh_key_path", "SSH key path"),
+    (
+        "wizard.signing_key_optional",
+        "GPG signing key (Enter to skip)",
+    ),
+    ("wizard.signing_key", "GPG signing key"),
+    ("which.mapped", "{} (mapping: {} -> {})"),
+    ("which.not_mapped", "No profile is mapped to: {}"),
+    ("list.empty", "No profiles registered."),
+    ("list.mappings", "Path mappings:"),
+    ("show_profile.resolved_header", "Profile '{}' (resolved):"),
+    ("show_profile.header", "Profile '{}':"),
+    ("profile.added", "Profile '{}' added."),
+    ("init.add_another", "Add another profile?"),
+    ("init.saved", "Config file saved: {}"),
+    ("init.created", "Config file created: {}"),
+    (
+        "init.edit_hint",
+        "Edit the config file to customize your profiles.",
+    ),
+    ("init.ssh_example", "SSH config example:"),
+    (
+        "add.detecting_username",
+        "Looking up the username via the GitHub API...",
+    ),
+    ("add.pat_later", "To add a GitHub PAT later:"),
+    ("profile.edited", "Profile '{}' updated."),
+    ("profile.renamed", "Profile renamed: {} -> {}"),
+    ("profile.mapping_updated", "Path mapping updated: {} -> {}"),
+    ("profile.removed", "Profile '{}' removed."),
+    ("map.added", "Path mapping added: {} -> {}"),
+    ("map.removed", "Path mapping removed: {}"),
+    ("map.not_found", "No mapping found for path: {}"),
+    ("credentials.cached_accounts", "Cached GitHub accounts:"),
+    ("credentials.no_cached_accounts", "(no cached accounts)"),
+    ("credentials.list_failed", "Failed to list accounts: {}"),
+    ("credentials.clearing", "Clearing credentials for '{}'..."),
+    ("credentials.cleared", "Credentials for '{}' cleared."),
+    (
+        "credentials.clearing_all",
+        "Clearing credentials for all GitHub accounts...",
+    ),
+    (
+        "credentials.cleared_all",
+        "Credentials for all GitHub accounts cleared.",
+    ),
+    ("cli.about", "Git user profile switcher"),
+    (
+        "cli.format",
+        "Output format (json prints results to stdout as JSON and progress events to stderr as JSON lines)",
+    ),
+    (
+        "cli.lang",
+        "Display language (default: language in the config file, then LC_ALL/LC_MESSAGES/LANG)",
+    ),
+    ("cli.quiet", "Print only warnings and errors"),
+    (
+        "cli.verbose",
+        "Also print detailed progress such as the git commands run",
+    ),
+    ("cli.use.about", "Apply a profile to the current repository"),
+    (
+        "cli.use.profile_name",
+        "Profile to apply (pick from a list if omitted)",
+    ),
+    ("cli.use.ssh", "Enable SSH integration"),
+    (
+        "cli.show.about",
+        "Show the profile applied to the current repository",
+    ),
+    (
+        "cli.status.about",
+        "Compare the effective identity with the expected profile (exits OK=0, MISMATCH=3, UNMAPPED=4)",
+    ),
+    ("cli.which.about", "Show the profile mapped to a path"),
+    (
+        "cli.which.path",
+        "Path to check (default: current directory)",
+    ),
+    ("cli.list.about", "List available profiles"),
+    ("cli.show-profile.about", "Show profile details"),
+    ("cli.show-profile.name", "Profile to show"),
+    (
+        "cli.show-profile.resolved",
+        "Show the result merged with extends and [defaults], with the origin of each value",
+    ),
+    ("cli.init.about", "Create the initial config file"),
+    (
+        "cli.init.interactive",
+        "Create profiles interactively instead of sample profiles",
+    ),
+    ("cli.auto.about", "Detect and apply a profile automatically"),
+    ("cli.auto.ssh", "Enable SSH integration"),
+    ("cli.add.about", "Add a profile"),
+    ("cli.add.name", "Profile name"),
+    ("cli.add.user_name", "User name"),
+    ("cli.add.email", "Email address"),
+    ("cli.add.github_username", "GitHub username (optional)"),
+    (
+        "cli.add.github_pat",
+        "GitHub Personal Access Token (optional)",
+    ),
+    ("cli.add.signing_key", "GPG signing key (optional)"),
+    ("cli.add.ssh_key", "SSH key path (optional)"),
+    ("cli.add.force", "Overwrite a profile with the same name"),
+    ("cli.add.interactive", "Enter each value interactively"),
+    (
+        "cli.edit.about",
+        "Change individual values of an existing profile",
+    ),
+    ("cli.edit.name", "Profile to edit"),
+    ("cli.edit.user_name", "User name"),
+    ("cli.edit.email", "Email address"),
+    ("cli.edit.github_username", "GitHub username"),
+    ("cli.edit.github_pat", "GitHub Personal Access Token"),
+    ("cli.edit.signing_key", "GPG signing key"),
+    ("cli.edit.ssh_key", "SSH key path"),
+    ("cli.edit.extends", "Parent profile to inherit from"),
+    ("cli.edit.unset", "Field to clear (can be repeated)"),
+    (
+        "cli.rename.about",
+        "Rename a profile (also updates path mappings and extends references)",
+    ),
+    ("cli.rename.old_name", "Current profile name"),
+    ("cli.rename.new_name", "New profile name"),
+    ("cli.remove.about", "Remove a profile"),
+    ("cli.remove.name", "Profile to remove"),
+    ("cli.map.about", "Add a path mapping (for auto detection)"),
+    (
+        "cli.map.path",
+        "Directory path (remote URL pattern with --remote)",
+    ),
+    ("cli.map.profile", "Profile to map"),
+    ("cli.unmap.about", "Remove a path mapping"),
+    (
+        "cli.unmap.path",
+        "Directory path to unmap (remote URL pattern with --remote)",
+    ),
+    ("cli.credentials.about", "Manage credentials"),
+    ("cli.credentials.list.about", "List cached GitHub accounts"),
+    (
+        "cli.credentials.clear.about",
+        "Clear credentials of a GitHub account",
+    ),
+    ("cli.credentials.clear.username", "GitHub username"),
+    (
+        "cli.credentials.clear-all.about",
+        "Clear credentials of all GitHub accounts",
+    ),
+    (
+        "error.invalid_mapping",
+        "Path '{}' is mapped to profile '{}', which does not exist",
+    ),
+    (
+        "error.credential_store",
+        "Failed to store GitHub credentials: {}",
+    ),
+    ("error.credential_erase", "Failed to erase credentials: {}"),
+    ("error.encryption", "Failed to encrypt token: {}"),
+    ("error.decryption", "The stored PAT is corrupted: {}"),
+    (
+        "error.key_mismatch",
+        "Cannot decrypt the stored PAT (encryption key differs)",
+    ),
+    ("error.network", "GitHub API request failed: {}"),
+    ("error.home_not_found", "Could not find the home directory"),
+    ("error.no_profiles", "No profiles registered"),
+    (
+        "error.not_interactive",
+        "Interactive mode requires a terminal",
+    ),
+    ("error.no_selection", "No profile selected"),
+    (
+        "hint.config_parse",
+        "Check the TOML syntax of the config file",
+    ),
+    (
+        "hint.not_git_repo",
+        "Run inside a Git repository or create one with 'git init'",
+    ),
+    (
+        "hint.profile_not_found",
+        "Run 'git-switcher list' to see registered profiles",
+    ),
+    (
+        "hint.profile_exists",
+        "Use --force to overwrite, or 'git-switcher edit {}' to change individual values",
+    ),
+    (
+        "hint.inheritance_cycle",
+        "Remove extends from one of the profiles in the cycle",
+    ),
+    (
+        "hint.incomplete_profile",
+        "Set it with 'git-switcher edit {} --{} <value>'",
+    ),
+    (
+        "hint.no_mapped_profile",
+        "Add a path mapping with 'git-switcher map <path> <profile>'",
+    ),
+    (
+        "hint.invalid_mapping",
+        "Remove it with 'git-switcher unmap {}' and map the path again",
+    ),
+    (
+        "hint.credential_store",
+        "Check the permissions of ~/.git-credentials and the credential.helper setting",
+    ),
+    ("hint.credential_erase", "Check that 'git credential' works"),
+    (
+        "hint.reenter_pat",
+        "Store the PAT again with 'git-switcher edit <profile> --github-pat <PAT>'",
+    ),
+    (
+        "hint.network",
+        "Check your network connection and that curl is installed",
+    ),
+    ("hint.home_not_found", "Check the HOME environment variable"),
+    (
+        "hint.no_profiles",
+        "Add a profile with 'git-switcher add' or 'git-switcher init'",
+    ),
+    (
+        "hint.not_interactive",
+        "Run in a terminal or pass the values as arguments",
+    ),
+    (
+        "hint.no_selection",
+        "Enter a number or name, or pass the profile as 'git-switcher use <name>'",
+    ),
+    ("git.curl_unavailable", "Could not run curl"),
+    ("error.caused_by", "Caused by: {}"),
+    (
+        "config.permissions_too_open",
+        "Config file {} is readable by other users (mode {})",
+    ),
+    (
+        "error.unsupported_config_version",
+        "Config file version {} is not supported by this git-switcher (newest supported: {})",
+    ),
+    (
+        "hint.unsupported_config_version",
+        "Update git-switcher to the latest version, or restore a config.toml.v*.bak backup made before migration",
+    ),
+    (
+        "config.migrated",
+        "Migrated config file from version {} to {} (original backed up to {})",
+    ),
+    (
+        "config.migration_needed",
+        "Config file is at version {}. Run 'git-switcher config migrate' to upgrade it to version {}",
+    ),
+    (
+        "config.up_to_date",
+        "Config file is up to date (version {})",
+    ),
+    ("cli.config.about", "Manage the config file"),
+    (
+        "cli.config.migrate.about",
+        "Upgrade an older config file to the current schema version (the original is backed up)",
+    ),
+    (
+        "cli.config.migrate.check",
+        "Only check whether a migration is needed (exit code 14 if so)",
+    ),
+    (
+        "error.profile_in_use",
+        "Cannot remove profile '{}' because it is still referenced ({})",
+    ),
+    ("error.profile_in_use_mappings", "path mappings: {}"),
+    ("error.profile_in_use_children", "profiles extending it: {}"),
+    (
+        "hint.profile_in_use_mappings",
+        "Run 'git-switcher remove {} --cascade' to remove the mappings too, or clean them up first with 'git-switcher unmap <path>'",
+    ),
+    (
+        "hint.profile_in_use_children",
+        "Point the extending profiles elsewhere with 'git-switcher edit <profile> --extends <other>' or remove them first",
+    ),
+    ("profile.mapping_removed", "Removed path mapping: {}"),
+    (
+        "validate.dangling_mapping",
+        "Path mapping '{}' points to nonexistent profile '{}'",
+    ),
+    (
+        "validate.missing_directory",
+        "Directory of path mapping '{}' does not exist",
+    ),
+    (
+        "validate.duplicate_mapping",
+        "Path mapping '{}' resolves to the same path as '{}'",
+    ),
+    (
+        "validate.missing_parent",
+        "Profile '{}' extends nonexistent profile '{}'",
+    ),
+    (
+        "validate.inheritance_cycle",
+        "Profile '{}' has an inheritance cycle: {}",
+    ),
+    ("validate.invalid_email", "{} has a malformed email '{}'"),
+    (
+        "validate.undecryptable_pat",
+        "Cannot decrypt the PAT in {}: {}",
+    ),
+    (
+        "validate.missing_ssh_key",
+        "{} refers to SSH key '{}' which does not exist",
+    ),
+    (
+        "validate.unknown_language",
+        "Unknown display language '{}' (expected ko or en)",
+    ),
+    ("validate.fixed", "fixed"),
+    ("validate.clean", "No problems found in the config file"),
+    ("validate.summary", "{} error(s), {} warning(s)"),
+    (
+        "validate.fixable",
+        "{} issue(s) can be fixed automatically with 'git-switcher config validate --fix'",
+    ),
+    (
+        "cli.remove.cascade",
+        "Also remove path mappings that point to the profile",
+    ),
+    (
+        "cli.config.validate.about",
+        "Report problems in the config file (dangling mappings, malformed emails, missing SSH keys, ...) with their locations",
+    ),
+    (
+        "cli.config.validate.fix",
+        "Automatically fix problems that are safe to repair",
+    ),
+    (
+        "cli.config",
+        "Config file to use (default: GIT_SWITCHER_CONFIG, otherwise $XDG_CONFIG_HOME/git-switcher/config.toml)",
+    ),
+    ("error.layer_parse", "Failed to parse config file {}"),
+    (
+        "error.system_managed",
+        "'{}' is defined in the system layer and cannot be removed or moved from the user config",
+    ),
+    (
+        "hint.system_managed",
+        "Ask an administrator to change the system config file, or override the key in your user config",
+    ),
+    (
+        "hint.policy_violation",
+        "Use a profile that satisfies the repository's .git-switcher.toml or the team policy rules (check the team policy with git-switcher verify)",
+    ),
+    (
+        "policy.required_profile",
+        "{} requires profile '{}' but '{}' was requested",
+    ),
+    (
+        "policy.email_domain",
+        "Email '{}' is not in the domains allowed by {} ({})",
+    ),
+    (
+        "layers.secret_ignored",
+        "Ignoring '{}' because the system layer may not hold secrets ({})",
+    ),
+    ("layers.files", "Config layers:"),
+    ("layers.missing", "not found"),
+    ("layers.settings", "Settings:"),
+    ("status.allowed_email_domains", "allowed domains: {} ({})"),
+    (
+        "cli.config.show.about",
+        "Show merged settings from the system, user and repository layers with the layer each value came from",
+    ),
+    (
+        "policy.email_domain_rule",
+        "Email '{}' is not in the allowed domains ({})",
+    ),
+    (
+        "policy.signing_required",
+        "A signing key and commit.gpgsign = true are required",
+    ),
+    (
+        "policy.ssh_required",
+        "An SSH remote URL and an SSH key (--ssh) are required",
+    ),
+    (
+        "policy.violation",
+        "Team policy violation ({}, rule '{}'): {}",
+    ),
+    ("verify.no_policy", "No team policy file is configured"),
+    (
+        "verify.no_policy_hint",
+        "Set the policy file path in the config, e.g. policy = \"~/dotfiles/git-policy.toml\"",
+    ),
+    ("verify.header", "Team policy check ({}):"),
+    (
+        "verify.no_matching_rules",
+        "No rules match this repository's remotes",
+    ),
+    ("verify.passed", "All {} matching rules are satisfied"),
+    (
+        "cli.verify.about",
+        "Check that the repository's effective settings satisfy the team policy (exits 13 on violation)",
+    ),
+    ("field.ssh_key", "SSH key: {}"),
+    ("git.read_file_failed", "Could not read config file {}"),
+    (
+        "import.condition_skipped",
+        "Skipping includeIf condition that cannot become a path mapping: {}",
+    ),
+    (
+        "import.source_root",
+        "all {} repositories use the same email",
+    ),
+    ("import.source_repo", "repository local config"),
+    (
+        "import.nothing",
+        "No new profiles or path mappings to import",
+    ),
+    ("import.profiles", "Profiles to import:"),
+    ("import.sources", "Found in: {}"),
+    ("import.mappings", "Path mappings to add:"),
+    (
+        "import.skipped",
+        "{} already belongs to profile '{}', not creating a new one",
+    ),
+    ("import.confirm", "Add these to the config file?"),
+    ("import.saved", "Added {} profiles and {} path mappings"),
+    ("import.cancelled", "Import cancelled"),
+    (
+        "cli.import.about",
+        "Import profiles and path mappings from existing git settings (~/.gitconfig, includeIf, repository configs, ~/.ssh/config)",
+    ),
+    (
+        "cli.import.roots",
+        "Directory to scan for repositories (repeatable, default: ~/work, ~/projects, ...)",
+    ),
+    ("cli.import.yes", "Save without asking for confirmation"),
+    (
+        "cli.import.dry_run",
+        "Only print the proposal without saving",
+    ),
+    ("error.invalid_bundle", "{} is not a usable bundle file: {}"),
+    (
+        "error.wrong_passphrase",
+        "The bundle passphrase is incorrect",
+    ),
+    (
+        "hint.wrong_passphrase",
+        "Enter the passphrase used for export, or import without tokens using --skip-tokens",
+    ),
+    (
+        "bundle.not_bundle",
+        "not a file created by git-switcher export",
+    ),
+    (
+        "bundle.newer_version",
+        "bundle version {} is newer than this build supports ({})",
+    ),
+    ("bundle.unknown_kdf", "unknown key derivation '{}'"),
+    (
+        "bundle.header",
+        "git-switcher profile bundle (load with git-switcher import-bundle)",
+    ),
+    (
+        "bundle.token_unreadable",
+        "Could not decrypt the PAT of profile '{}', leaving it out of the bundle: {}",
+    ),
+    (
+        "bundle.exported",
+        "Exported {} with {} profiles, {} path mappings and {} tokens",
+    ),
+    ("bundle.imported", "Merged {} into the config"),
+    ("bundle.dry_run", "Nothing was saved (--dry-run)"),
+    ("bundle.profiles", "Profiles:"),
+    ("bundle.mappings", "Path mappings:"),
+    ("bundle.added", "added"),
+    ("bundle.unchanged", "unchanged"),
+    ("bundle.skipped", "kept existing"),
+    ("bundle.overwritten", "overwritten"),
+    ("bundle.renamed", "added under a new name"),
+    (
+        "bundle.tokens",
+        "Re-encrypted {} tokens with this machine's key",
+    ),
+    (
+        "bundle.tokens_skipped",
+        "{} tokens were not imported (enter them again with edit --github-pat)",
+    ),
+    ("wizard.passphrase", "Bundle passphrase"),
+    ("wizard.passphrase_confirm", "Confirm bundle passphrase"),
+    ("wizard.passphrase_mismatch", "Passphrases do not match"),
+    (
+        "cli.export.about",
+        "Export profiles and path mappings to a bundle file for another machine",
+    ),
+    ("cli.export.file", "Bundle file path"),
+    (
+        "cli.export.profiles",
+        "Profile to export (repeatable, default: all, parents included)",
+    ),
+    (
+        "cli.export.include_tokens",
+        "Also include PATs, re-encrypted with a bundle passphrase",
+    ),
+    (
+        "cli.import-bundle.about",
+        "Merge a bundle created by export into the current config",
+    ),
+    ("cli.import-bundle.file", "Bundle file path"),
+    (
+        "cli.import-bundle.on_conflict",
+        "What to do when a profile name or mapped path already exists",
+    ),
+    (
+        "cli.import-bundle.on_conflict.skip",
+        "Keep the existing value",
+    ),
+    (
+        "cli.import-bundle.on_conflict.overwrite",
+        "Replace it with the value from the bundle",
+    ),
+    (
+        "cli.import-bundle.on_conflict.rename",
+        "Add the bundle's profile as `<name>-imported` (path mappings keep the existing value)",
+    ),
+    (
+        "cli.import-bundle.skip_tokens",
+        "Do not import PATs from the bundle (no passphrase prompt)",
+    ),
+    (
+        "cli.import-bundle.dry_run",
+        "Only print the merge result without saving",
+    ),
+    ("list.remote_mappings", "Remote mappings:"),
+    (
+        "cli.map.remote",
+        "Map a remote URL pattern (github.com/acme-corp/*) instead of a path (used by clone)",
+    ),
+    ("cli.unmap.remote", "Remove a remote URL mapping"),
+    ("git.clone_failed", "Failed to clone repository: {}"),
+    (
+        "clone.no_directory",
+        "Cannot derive a directory name from {} (pass a directory)",
+    ),
+    (
+        "clone.directory_not_empty",
+        "Directory already exists and is not empty: {}",
+    ),
+    ("clone.not_ssh_url", "Cannot convert remote URL to SSH: {}"),
+    ("clone.detected", "🔍 Using profile '{}' (mapping: {})"),
+    ("clone.cloning", "Cloning {} into {}..."),
+    ("clone.using_alias", "Using SSH alias '{}'"),
+    (
+        "clone.using_ssh_key",
+        "Using SSH key {} (saved as core.sshCommand)",
+    ),
+    ("clone.using_pat", "Using the PAT of GitHub account '{}'"),
+    (
+        "clone.alias_hint",
+        "Add a Host alias using the profile key to ~/.ssh/config to clone via the alias instead of core.sshCommand",
+    ),
+    (
+        "cli.clone.about",
+        "Clone a repository with the mapped profile's identity and credentials, then apply the profile",
+    ),
+    ("cli.clone.url", "Remote URL to clone"),
+    (
+        "cli.clone.dir",
+        "Directory to clone into (default: repository name from the URL)",
+    ),
+    (
+        "cli.clone.profile",
+        "Profile to use (default: remote mapping, then the directory's path mapping)",
+    ),
+    (
+        "cli.clone.ssh",
+        "Convert HTTPS URLs to SSH and clone with the profile's SSH key",
+    ),
+    ("git.worktree_failed", "Failed to list worktrees"),
+    ("git.submodules_failed", "Failed to list submodules"),
+    (
+        "apply.per_worktree",
+        "Applied to this worktree's config (config.worktree)",
+    ),
+    (
+        "apply.shared_worktrees",
+        "This repository's local config is shared by {} worktrees, so the profile applies to all of them",
+    ),
+    (
+        "apply.per_worktree_hint",
+        "Use --per-worktree to apply a different profile per worktree",
+    ),
+    ("submodule.none", "No initialized submodules"),
+    ("submodule.processing", "Submodule: {}"),
+    (
+        "submodule.failed",
+        "Failed to apply to {} submodules (of {})",
+    ),
+    (
+        "cli.use.per_worktree",
+        "Enable extensions.worktreeConfig and apply to the current worktree only",
+    ),
+    (
+        "cli.use.recurse_submodules",
+        "Also apply to submodules (using their remote mapping if any)",
+    ),
+    (
+        "cli.auto.per_worktree",
+        "Enable extensions.worktreeConfig and apply to the current worktree only",
+    ),
+    (
+        "cli.auto.recurse_submodules",
+        "Also apply to submodules (using their remote mapping if any)",
+    ),
+    ("git.log_failed", "Failed to list commits ({}): {}"),
+    ("git.unknown_revision", "Unknown revision: {}"),
+    (
+        "git.commit_tree_failed",
+        "Failed to create commit (tree {})",
+    ),
+    ("git.update_ref_failed", "Failed to update {}"),
+    (
+        "history.operation_in_progress",
+        "A rebase, merge or similar operation is in progress; finish or abort it first",
+    ),
+    (
+        "error.pushed_commits",
+        "{} of the commits to rewrite have already been pushed",
+    ),
+    (
+        "hint.pushed_commits",
+        "Rewriting pushed commits requires a force push; rerun with --force if you are sure",
+    ),
+    (
+        "audit.header",
+        "🔍 Commit identity audit (profile '{}', {})",
+    ),
+    ("audit.range", "Range: {}"),
+    ("audit.unpushed", "unpushed commits"),
+    ("audit.author", "Author: {} <{}>"),
+    ("audit.committer", "Committer: {} <{}>"),
+    ("audit.pushed", "(already pushed)"),
+    (
+        "audit.clean",
+        "All commits match the profile email ({} checked)",
+    ),
+    (
+        "audit.found",
+        "{} commits were made under a different identity (of {})",
+    ),
+    (
+        "audit.fix_hint",
+        "Run 'git-switcher fix-authors' to fix the unpushed commits",
+    ),
+    ("fix_authors.nothing", "Nothing to fix (profile '{}', {})"),
+    (
+        "fix_authors.header",
+        "These commits will be rewritten to {} <{}> (profile '{}'):",
+    ),
+    (
+        "fix_authors.pushed",
+        "{} of the commits to be recreated are already pushed",
+    ),
+    ("fix_authors.confirm", "Rewrite these commits?"),
+    ("fix_authors.done", "Rewrote {} commits"),
+    (
+        "fix_authors.undo_hint",
+        "Run 'git reset --keep ORIG_HEAD' to undo",
+    ),
+    ("fix_authors.cancelled", "Rewrite cancelled"),
+    (
+        "fix_authors.signatures_dropped",
+        "Signatures were dropped from {} signed commits (use --sign to re-sign)",
+    ),
+    (
+        "cli.audit.about",
+        "List commits whose author/committer email differs from the repository's expected profile (exits 3 if any)",
+    ),
+    (
+        "cli.audit.range",
+        "Commit range to check (e.g. origin/main..HEAD, default: commits not on any remote-tracking branch)",
+    ),
+    (
+        "cli.audit.profile",
+        "Expected profile (default: path mapping)",
+    ),
+    (
+        "cli.fix-authors.about",
+        "Rewrite unpushed commits made under another identity to the profile's identity",
+    ),
+    (
+        "cli.fix-authors.base",
+        "Rewrite commits after this one (<base>..HEAD, default: commits not on any remote-tracking branch)",
+    ),
+    (
+        "cli.fix-authors.profile",
+        "Profile to use (default: path mapping)",
+    ),
+    (
+        "cli.fix-authors.sign",
+        "Sign the rewritten commits with the profile's signing key",
+    ),
+    (
+        "cli.fix-authors.force",
+        "Also rewrite commits that were already pushed (requires a force push)",
+    ),
+    (
+        "cli.fix-authors.yes",
+        "Rewrite without asking for confirmation",
+    ),
+    (
+        "cli.fix-authors.dry_run",
+        "Only print the commits that would be rewritten",
+    ),
+    ("mailmap.header", "Mapping these emails to {} <{}>:"),
+    (
+        "mailmap.skipped",
+        "Skipping {}: already mapped by a hand-written entry",
+    ),
+    ("mailmap.written", "Updated {}"),
+    ("mailmap.unchanged", "{} is already up to date"),
+    ("mailmap.dry_run", "Not saved (--dry-run)"),
+    (
+        "cli.mailmap.about",
+        "Generate or update a .mailmap block that maps the profiles' emails to one name/email",
+    ),
+    (
+        "cli.mailmap.profiles",
+        "Profiles to include (repeatable, default: all profiles)",
+    ),
+    (
+        "cli.mailmap.canonical",
+        "Profile whose name/email is canonical (default: profile mapped to the current path)",
+    ),
+    (
+        "cli.mailmap.file",
+        "File to update (default: .mailmap at the repository root)",
+    ),
+    (
+        "cli.mailmap.dry_run",
+        "Only print the result without saving",
+    ),
+    (
+        "activity.write_failed",
+        "Could not write the activity log: {}",
+    ),
+    (
+        "activity.invalid_line",
+        "Skipping an unreadable activity log line: {}",
+    ),
+    ("log.empty", "No matching activity"),
+    ("log.all_accounts", "all accounts"),
+    ("log.error", "Error: {}"),
+    (
+        "log.invalid_since",
+        "Cannot parse --since value: {} (use 7d, 12h, 2026-10-01 or 2026-10-01T09:00:00Z)",
+    ),
+    (
+        "cli.log.about",
+        "Show recorded profile applications, credential stores/erases and policy checks",
+    ),
+    (
+        "cli.log.repo",
+        "Only repositories at or under this path (without a path: the current repository)",
+    ),
+    ("cli.log.profile", "Only entries for this profile"),
+    (
+        "cli.log.since",
+        "Only entries after this time (`7d`, `12h`, `2026-10-01`, `2026-10-01T09:00:00Z`, in UTC)",
+    ),
+    ("exec.running", "Running {} as profile '{}'"),
+    ("exec.exit_status", "Command exited with status {}"),
+    ("exec.no_command", "No command to run"),
+    ("exec.spawn_failed", "Cannot run {}: {}"),
+    (
+        "cli.exec.about",
+        "Run one command with a profile's identity and credentials without changing repository config",
+    ),
+    ("cli.exec.profile", "Profile to use"),
+    ("cli.exec.command", "Command to run (after `--`)"),
+];
@@ -0,0 +1,635 @@
+/// 한국어 메시지 카탈로그 (기본 언어)
+///
+/// 명령줄 도움말(`cli.*`)은 `cli.rs`의 문서 주석을 그대로 사용
+pub const MESSAGES: &[(&str, &str)] = &[
+    ("error.io", "입출력 에러"),
+    ("error.config_parse", "설정 파일 파싱 에러"),
+    ("error.config_serialize", "TOML 직렬화 에러"),
+    ("error.json_serialize", "JSON 직렬화 에러"),
+    ("error.git", "Git 에러: {}"),
+    (
+        "error.not_git_repo",
+        "현재 디렉토리는 Git 저장소가 아닙니다",
+    ),
+    ("error.profile_not_found", "프로필 '{}'을 찾을 수 없습니다"),
+    ("error.profile_exists", "프로필 '{}'이 이미 존재합니다"),
+    ("error.inheritance_cycle", "프로필 상속이 순환합니다: {}"),
+    (
+        "error.incomplete_profile",
+        "프로필 '{}'에 '{}' 값이 없습니다 (extends 또는 [defaults]로도 지정되지 않음)",
+    ),
+    (
+        "error.config_not_found",
+        "설정 파일이 없습니다. 'git-switcher init' 명령으로 초기화하세요",
+    ),
+    (
+        "error.no_mapped_profile",
+        "현재 경로에 매핑된 프로필이 없습니다: {}",
+    ),
+    ("profile.decrypt_failed", "(복호화 실패)"),
+    ("status.none", "(없음)"),
+    ("status.expected_local", "기대값: {}, 로컬 값: {}"),
+    ("status.expected_actual", "기대값: {}, 실제 값: {}"),
+    ("status.credentials_stored", "{}@github.com 크리덴셜 저장됨"),
+    (
+        "status.credentials_missing",
+        "{}@github.com 크리덴셜이 저장되어 있지 않습니다",
+    ),
+    ("status.ssh_key_exists", "SSH 키 존재: {}"),
+    ("status.ssh_key_missing", "SSH 키 파일이 없습니다: {}"),
+    ("status.no_ssh_remotes", "SSH를 사용하는 리모트 없음"),
+    ("status.ssh_remotes", "SSH 리모트: {}"),
+    ("crypto.decode_failed", "토큰 디코딩 실패: {}"),
+    ("crypto.invalid_format", "잘못된 암호화 데이터 형식"),
+    ("crypto.utf8_failed", "토큰 문자열 변환 실패: {}"),
+    (
+        "ssh.example_header",
+        "~/.ssh/config 파일에 추가할 설정 예시",
+    ),
+    ("ssh.example_host", "{} 프로필용 {} 설정"),
+    ("credentials.pat_setup", "GitHub PAT 자동 설정 중..."),
+    (
+        "credentials.pat_maybe_invalid",
+        "PAT가 유효하지 않을 수 있습니다. 수동으로 확인해주세요.",
+    ),
+    ("git.set_failed", "{} 설정 실패"),
+    ("git.get_failed", "{}을 가져올 수 없습니다"),
+    ("git.unset_failed", "{} 제거 실패"),
+    ("git.remotes_failed", "리모트 정보를 가져올 수 없습니다"),
+    ("git.set_remote_url_failed", "리모트 '{}' URL 설정 실패"),
+    ("git.cached_credentials_cleared", "캐시된 크리덴셜 삭제: {}"),
+    (
+        "git.credentials_not_cached",
+        "크리덴셜이 캐시되지 않았음: {}",
+    ),
+    ("git.no_cached_accounts", "캐시된 GitHub 계정이 없습니다"),
+    (
+        "git.clearing_cached_accounts",
+        "캐시된 GitHub 계정들을 삭제합니다: {}",
+    ),
+    (
+        "git.clear_all_unsupported",
+        "Linux/macOS에서는 개별 계정 삭제만 지원됩니다",
+    ),
+    ("git.erase_done", "크리덴셜 삭제 완료: {}@{}"),
+    ("git.erase_not_stored", "크리덴셜이 저장되지 않았음: {}@{}"),
+    ("git.store_done", "GitHub 크리덴셜 저장 완료: {}"),
+    ("git.credentials_location", "저장 위치: {}"),
+    ("git.github_api_parse_failed", "GitHub API 응답 파싱 실패"),
+    ("apply.clearing_credentials", "기존 크리덴셜 정리 중..."),
+    ("apply.applied", "프로필이 적용되었습니다."),
+    ("field.name", "이름: {}"),
+    ("field.email", "이메일: {}"),
+    ("field.gpg_key", "GPG 키: {}"),
+    ("apply.extra_git_config", "추가 Git 설정:"),
+    ("apply.ssh_enabled", "SSH 연동: 활성화됨"),
+    (
+        "apply.pat_configured",
+        "PAT가 자동으로 설정되었습니다. push가 바로 가능합니다!",
+    ),
+    (
+        "apply.pat_required",
+        "다음 push 시 새로운 PAT 입력이 필요합니다",
+    ),
+    ("show.current_profile", "현재 Git 프로필:"),
+    (
+        "show.no_profile",
+        "현재 저장소에 Git 프로필이 설정되어 있지 않습니다.",
+    ),
+    ("status.repository", "저장소: {}"),
+    (
+        "status.applied_settings",
+        "적용된 Git 설정 (우선순위 높은 순):",
+    ),
+    ("status.not_set", "(설정되지 않음)"),
+    ("status.current_profile", "현재 설정에 해당하는 프로필: {}"),
+    ("status.no_matching_profile", "(일치하는 프로필 없음)"),
+    ("status.expected_profile", "경로 규칙이 기대하는 프로필: {}"),
+    ("status.no_mapping", "(매핑 없음)"),
+    ("status.checks", "점검 항목:"),
+    ("status.result", "결과: {}"),
+    ("auto.detected", "자동 감지된 프로필: {}"),
+    ("auto.path", "경로: {}"),
+    ("auto.available_mappings", "사용 가능한 경로 매핑:"),
+    ("auto.no_mappings", "(매핑된 경로가 없습니다)"),
+    ("auto.how_to_map", "경로 매핑을 추가하려면:"),
+    ("auto.map_usage", "git-switcher map <경로> <프로필>"),
+    (
+        "auto.map_example",
+        "예: git-switcher map ~/workspace/company company",
+    ),
+    (
+        "auto.no_repos",
+        "매핑된 경로에서 Git 저장소를 찾을 수 없습니다.",
+    ),
+    ("auto.found_repos", "발견된 Git 저장소들:"),
+    ("auto.processing", "처리 중: {}"),
+    ("auto.chdir_failed", "디렉토리 변경 실패: {}"),
+    ("auto.applied", "{} 프로필 적용 완료"),
+    ("auto.apply_failed", "프로필 적용 실패: {}"),
+    ("auto.load_failed", "프로필 로드 실패: {}"),
+    ("picker.applied", "현재 적용됨"),
+    ("picker.mapped", "경로 매핑"),
+    ("picker.prompt", "적용할 프로필 (입력하여 검색)"),
+    ("picker.available", "사용 가능한 프로필:"),
+    ("picker.numbered_prompt", "번호 또는 이름을 입력하세요"),
+    ("wizard.profile_name", "프로필 이름"),
+    ("wizard.profile_name_required", "프로필 이름을 입력하세요"),
+    ("wizard.user_name", "사용자 이름"),
+    ("wizard.email", "이메일 주소"),
+    ("wizard.email_invalid", "올바른 이메일 주소를 입력하세요"),
+    ("wizard.github_username", "GitHub 사용자명 (없으면 Enter)"),
+    ("wizard.github_pat", "GitHub PAT (없으면 Enter)"),
+    (
+        "pat.format_warning",
+        "경고: 입력된 토큰이 올바른 GitHub PAT 형식이 아닐 수 있습니다.",
+    ),
+    ("wizard.verify_pat", "GitHub API로 토큰을 확인할까요?"),
+    ("pat.valid", "토큰이 유효합니다."),
+    ("pat.username_detected", "GitHub 사용자명 자동 감지: {}"),
+    (
+        "wizard.pat_unverified",
+        "토큰을 확인하지 못했습니다. 나중에 다시 확인해주세요.",
+    ),
+    ("pat.stored", "GitHub PAT가 암호화되어 저장되었습니다: {}"),
+    (
+        "wizard.map_current_dir",
+        "현재 디렉토리를 이 프로필에 매핑할까요? ({})",
+    ),
+    ("wizard.ssh_key_path_optional", "SSH 키 경로 (없으면 Enter)"),
+    ("wizard.none", "(사용 안 함)"),
+    ("wizard.enter_manually", "직접 입력"),
+    ("wizard.ssh_key", "SSH 키"),
+    ("wizard.ssh_key_path", "SSH 키 경로"),
+    ("wizard.signing_key_optional", "GPG 서명 키 (없으면 Enter)"),
+    ("wizard.signing_key", "GPG 서명 키"),
+    ("which.mapped", "{} (매핑: {} -> {})"),
+    ("which.not_mapped", "매핑된 프로필이 없습니다: {}"),
+    ("list.empty", "등록된 프로필이 없습니다."),
+    ("list.mappings", "경로 매핑:"),
+    ("show_profile.resolved_header", "프로필 '{}' (병합 결과):"),
+    ("show_profile.header", "프로필 '{}':"),
+    ("profile.added", "프로필 '{}'이 추가되었습니다."),
+    ("init.add_another", "다른 프로필도 추가할까요?"),
+    ("init.saved", "설정 파일이 저장되었습니다: {}"),
+    ("init.created", "설정 파일이 생성되었습니다: {}"),
+    (
+        "init.edit_hint",
+        "설정 파일을 편집하여 프로필을 수정하세요.",
+    ),
+    ("init.ssh_example", "SSH 설정 예시:"),
+    (
+        "add.detecting_username",
+        "GitHub API를 통해 사용자명 확인 중...",
+    ),
+    ("add.pat_later", "GitHub PAT를 나중에 추가하려면:"),
+    ("profile.edited", "프로필 '{}'이 수정되었습니다."),
+    ("profile.renamed", "프로필 이름이 변경되었습니다: {} -> {}"),
+    ("profile.mapping_updated", "경로 매핑 갱신: {} -> {}"),
+    ("profile.removed", "프로필 '{}'이 제거되었습니다."),
+    ("map.added", "경로 매핑이 추가되었습니다: {} -> {}"),
+    ("map.removed", "경로 매핑이 제거되었습니다: {}"),
+    ("map.not_found", "해당 경로의 매핑을 찾을 수 없습니다: {}"),
+    ("credentials.cached_accounts", "캐시된 GitHub 계정들:"),
+    ("credentials.no_cached_accounts", "(캐시된 계정이 없습니다)"),
+    ("credentials.list_failed", "계정 목록 조회 실패: {}"),
+    ("credentials.clearing", "계정 '{}' 크리덴셜 삭제 중..."),
+    (
+        "credentials.cleared",
+        "계정 '{}' 크리덴셜이 삭제되었습니다.",
+    ),
+    (
+        "credentials.clearing_all",
+        "모든 GitHub 계정 크리덴셜 삭제 중...",
+    ),
+    (
+        "credentials.cleared_all",
+        "모든 GitHub 계정 크리덴셜이 삭제되었습니다.",
+    ),
+    (
+        "error.invalid_mapping",
+        "경로 '{}'이 존재하지 않는 프로필 '{}'에 매핑되어 있습니다",
+    ),
+    ("error.credential_store", "GitHub 크리덴셜 저장 실패: {}"),
+    ("error.credential_erase", "크리덴셜 삭제 실패: {}"),
+    ("error.encryption", "토큰 암호화 실패: {}"),
+    ("error.decryption", "저장된 PAT가 손상되었습니다: {}"),
+    (
+        "error.key_mismatch",
+        "저장된 PAT를 복호화할 수 없습니다 (암호화 키가 다름)",
+    ),
+    ("error.network", "GitHub API 요청 실패: {}"),
+    ("error.home_not_found", "홈 디렉토리를 찾을 수 없습니다"),
+    ("error.no_profiles", "등록된 프로필이 없습니다"),
+    (
+        "error.not_interactive",
+        "대화형 모드는 터미널에서만 사용할 수 있습니다",
+    ),
+    ("error.no_selection", "프로필을 선택하지 않았습니다"),
+    ("hint.config_parse", "설정 파일의 TOML 문법을 확인하세요"),
+    (
+        "hint.not_git_repo",
+        "Git 저장소 안에서 실행하거나 'git init'으로 저장소를 만드세요",
+    ),
+    (
+        "hint.profile_not_found",
+        "'git-switcher list'로 등록된 프로필을 확인하세요",
+    ),
+    (
+        "hint.profile_exists",
+        "덮어쓰려면 --force를, 일부 값만 바꾸려면 'git-switcher edit {}'을 사용하세요",
+    ),
+    (
+        "hint.inheritance_cycle",
+        "순환에 포함된 프로필 중 하나의 extends를 제거하세요",
+    ),
+    (
+        "hint.incomplete_profile",
+        "'git-switcher edit {} --{} <값>'으로 값을 지정하세요",
+    ),
+    (
+        "hint.no_mapped_profile",
+        "'git-switcher map <경로> <프로필>'로 경로 매핑을 추가하세요",
+    ),
+    (
+        "hint.invalid_mapping",
+        "'git-switcher unmap {}'으로 매핑을 제거한 뒤 다시 매핑하세요",
+    ),
+    (
+        "hint.credential_store",
+        "~/.git-credentials 파일 권한과 credential.helper 설정을 확인하세요",
+    ),
+    (
+        "hint.credential_erase",
+        "'git credential' 명령이 동작하는지 확인하세요",
+    ),
+    (
+        "hint.reenter_pat",
+        "'git-switcher edit <프로필> --github-pat <PAT>'로 PAT를 다시 저장하세요",
+    ),
+    (
+        "hint.network",
+        "네트워크 연결과 curl 설치 여부를 확인하세요",
+    ),
+    ("hint.home_not_found", "HOME 환경 변수를 확인하세요"),
+    (
+        "hint.no_profiles",
+        "'git-switcher add' 또는 'git-switcher init'으로 프로필을 추가하세요",
+    ),
+    (
+        "hint.not_interactive",
+        "터미널에서 실행하거나 값을 인자로 지정하세요",
+    ),
+    (
+        "hint.no_selection",
+        "번호나 이름을 입력하거나 'git-switcher use <이름>'으로 프로필을 지정하세요",
+    ),
+    ("git.curl_unavailable", "curl을 실행할 수 없습니다"),
+    ("error.caused_by", "원인: {}"),
+    (
+        "config.permissions_too_open",
+        "설정 파일 {}을 다른 사용자가 읽을 수 있습니다 (권한 {})",
+    ),
+    (
+        "error.unsupported_config_version",
+        "설정 파일 버전 {}은(는) 이 git-switcher가 지원하지 않습니다 (지원하는 최신 버전: {})",
+    ),
+    (
+        "hint.unsupported_config_version",
+        "git-switcher를 최신 버전으로 업데이트하거나, 변환 전에 만든 config.toml.v*.bak 백업을 복원하세요",
+    ),
+    (
+        "config.migrated",
+        "설정 파일을 버전 {}에서 {}(으)로 변환했습니다 (원본 백업: {})",
+    ),
+    (
+        "config.migration_needed",
+        "설정 파일이 버전 {}입니다. 'git-switcher config migrate'로 버전 {}(으)로 변환하세요",
+    ),
+    ("config.up_to_date", "설정 파일이 최신 버전({})입니다"),
+    (
+        "error.profile_in_use",
+        "프로필 '{}'을(를) 참조하는 곳이 있어 제거할 수 없습니다 ({})",
+    ),
+    ("error.profile_in_use_mappings", "경로 매핑: {}"),
+    ("error.profile_in_use_children", "상속하는 프로필: {}"),
+    (
+        "hint.profile_in_use_mappings",
+        "'git-switcher remove {} --cascade'로 매핑까지 함께 제거하거나, 먼저 'git-switcher unmap <경로>'로 매핑을 정리하세요",
+    ),
+    (
+        "hint.profile_in_use_children",
+        "상속하는 프로필의 extends를 'git-switcher edit <프로필> --extends <다른 프로필>'로 바꾸거나 먼저 제거하세요",
+    ),
+    ("profile.mapping_removed", "경로 매핑 제거: {}"),
+    (
+        "validate.dangling_mapping",
+        "경로 매핑 '{}'이(가) 존재하지 않는 프로필 '{}'을(를) 가리킵니다",
+    ),
+    (
+        "validate.missing_directory",
+        "경로 매핑 '{}'의 디렉토리가 존재하지 않습니다",
+    ),
+    (
+        "validate.duplicate_mapping",
+        "경로 매핑 '{}'은(는) '{}'과(와) 같은 경로입니다",
+    ),
+    (
+        "validate.missing_parent",
+        "프로필 '{}'이(가) 상속하는 프로필 '{}'이(가) 없습니다",
+    ),
+    (
+        "validate.inheritance_cycle",
+        "프로필 '{}'의 상속이 순환합니다: {}",
+    ),
+    (
+        "validate.invalid_email",
+        "{}의 이메일 '{}'이(가) 올바른 형식이 아닙니다",
+    ),
+    (
+        "validate.undecryptable_pat",
+        "{}의 PAT를 복호화할 수 없습니다: {}",
+    ),
+    (
+        "validate.missing_ssh_key",
+        "{}의 SSH 키 '{}'이(가) 존재하지 않습니다",
+    ),
+    (
+        "validate.unknown_language",
+        "표시 언어 '{}'을(를) 알 수 없습니다 (ko 또는 en)",
+    ),
+    ("validate.fixed", "고침"),
+    ("validate.clean", "설정 파일에 문제가 없습니다"),
+    ("validate.summary", "오류 {}개, 경고 {}개"),
+    (
+        "validate.fixable",
+        "'git-switcher config validate --fix'로 {}개를 자동으로 고칠 수 있습니다",
+    ),
+    ("error.layer_parse", "설정 파일 {}을(를) 해석할 수 없습니다"),
+    (
+        "error.system_managed",
+        "'{}'은(는) 시스템 레이어에서 정의된 값이라 사용자 설정에서 지우거나 옮길 수 없습니다",
+    ),
+    (
+        "hint.system_managed",
+        "시스템 설정 파일을 관리자에게 수정 요청하거나, 같은 키를 사용자 설정에서 덮어쓰세요",
+    ),
+    (
+        "hint.policy_violation",
+        "저장소의 .git-switcher.toml이나 팀 정책 파일의 규칙에 맞는 프로필을 사용하세요 (팀 정책은 git-switcher verify로 확인)",
+    ),
+    (
+        "policy.required_profile",
+        "{}은(는) 프로필 '{}'을(를) 요구하지만 '{}'을(를) 적용하려고 했습니다",
+    ),
+    (
+        "policy.email_domain",
+        "이메일 '{}'은(는) {}에서 허용하는 도메인({})이 아닙니다",
+    ),
+    (
+        "layers.secret_ignored",
+        "시스템 레이어에는 비밀 값을 둘 수 없어 '{}'을(를) 무시합니다 ({})",
+    ),
+    ("layers.files", "설정 레이어:"),
+    ("layers.missing", "없음"),
+    ("layers.settings", "설정 값:"),
+    ("status.allowed_email_domains", "허용 도메인: {} ({})"),
+    (
+        "policy.email_domain_rule",
+        "이메일 '{}'은(는) 허용 도메인({})이 아닙니다",
+    ),
+    (
+        "policy.signing_required",
+        "서명 키와 commit.gpgsign = true가 필요합니다",
+    ),
+    (
+        "policy.ssh_required",
+        "SSH 원격 주소와 SSH 키(--ssh)가 필요합니다",
+    ),
+    ("policy.violation", "팀 정책 위반 ({}, 규칙 '{}'): {}"),
+    ("verify.no_policy", "팀 정책 파일이 설정되지 않았습니다"),
+    (
+        "verify.no_policy_hint",
+        "설정 파일에 policy = \"~/dotfiles/git-policy.toml\"처럼 정책 파일 경로를 지정하세요",
+    ),
+    ("verify.header", "팀 정책 확인 ({}):"),
+    (
+        "verify.no_matching_rules",
+        "이 저장소의 원격에 해당하는 규칙이 없습니다",
+    ),
+    ("verify.passed", "해당하는 규칙 {}개를 모두 만족합니다"),
+    ("field.ssh_key", "SSH 키: {}"),
+    (
+        "git.read_file_failed",
+        "설정 파일 {}을(를) 읽을 수 없습니다",
+    ),
+    (
+        "import.condition_skipped",
+        "경로 매핑으로 바꿀 수 없는 includeIf 조건을 건너뜁니다: {}",
+    ),
+    ("import.source_root", "저장소 {}개가 모두 같은 이메일 사용"),
+    ("import.source_repo", "저장소 로컬 설정"),
+    (
+        "import.nothing",
+        "가져올 새 프로필이나 경로 매핑이 없습니다",
+    ),
+    ("import.profiles", "가져올 프로필:"),
+    ("import.sources", "출처: {}"),
+    ("import.mappings", "추가할 경로 매핑:"),
+    (
+        "import.skipped",
+        "{}은(는) 이미 프로필 '{}'에 있어 새로 만들지 않습니다",
+    ),
+    ("import.confirm", "설정 파일에 추가할까요?"),
+    (
+        "import.saved",
+        "프로필 {}개와 경로 매핑 {}개를 추가했습니다",
+    ),
+    ("import.cancelled", "가져오기를 취소했습니다"),
+    (
+        "error.invalid_bundle",
+        "{}은(는) 사용할 수 없는 번들 파일입니다: {}",
+    ),
+    ("error.wrong_passphrase", "번들 암호가 맞지 않습니다"),
+    (
+        "hint.wrong_passphrase",
+        "export할 때 입력한 암호를 입력하거나, --skip-tokens로 PAT 없이 가져오세요",
+    ),
+    (
+        "bundle.not_bundle",
+        "git-switcher export로 만든 파일이 아닙니다",
+    ),
+    (
+        "bundle.newer_version",
+        "번들 버전 {}은(는) 이 버전이 읽을 수 있는 버전({})보다 새롭습니다",
+    ),
+    ("bundle.unknown_kdf", "알 수 없는 키 유도 방식 '{}'"),
+    (
+        "bundle.header",
+        "git-switcher 프로필 번들 (git-switcher import-bundle로 가져오기)",
+    ),
+    (
+        "bundle.token_unreadable",
+        "프로필 '{}'의 PAT를 복호화할 수 없어 번들에 포함하지 않습니다: {}",
+    ),
+    (
+        "bundle.exported",
+        "{}에 프로필 {}개, 경로 매핑 {}개, PAT {}개를 내보냈습니다",
+    ),
+    ("bundle.imported", "{}의 내용을 설정에 병합했습니다"),
+    ("bundle.dry_run", "--dry-run이므로 저장하지 않았습니다"),
+    ("bundle.profiles", "프로필:"),
+    ("bundle.mappings", "경로 매핑:"),
+    ("bundle.added", "추가"),
+    ("bundle.unchanged", "변경 없음"),
+    ("bundle.skipped", "기존 값 유지"),
+    ("bundle.overwritten", "덮어씀"),
+    ("bundle.renamed", "이름 바꿔 추가"),
+    (
+        "bundle.tokens",
+        "PAT {}개를 이 컴퓨터의 키로 다시 암호화해 저장했습니다",
+    ),
+    (
+        "bundle.tokens_skipped",
+        "PAT {}개는 가져오지 않았습니다 (edit --github-pat으로 다시 입력)",
+    ),
+    ("wizard.passphrase", "번들 암호"),
+    ("wizard.passphrase_confirm", "번들 암호 확인"),
+    ("wizard.passphrase_mismatch", "암호가 일치하지 않습니다"),
+    ("list.remote_mappings", "원격 주소 매핑:"),
+    ("git.clone_failed", "저장소 복제 실패: {}"),
+    (
+        "clone.no_directory",
+        "주소에서 디렉토리 이름을 정할 수 없습니다: {} (디렉토리를 지정하세요)",
+    ),
+    (
+        "clone.directory_not_empty",
+        "디렉토리가 이미 있고 비어 있지 않습니다: {}",
+    ),
+    (
+        "clone.not_ssh_url",
+        "SSH 주소로 바꿀 수 없는 원격 주소입니다: {}",
+    ),
+    ("clone.detected", "🔍 프로필 '{}' 사용 (매핑: {})"),
+    ("clone.cloning", "{} -> {} 복제 중..."),
+    ("clone.using_alias", "SSH 별칭 '{}' 사용"),
+    (
+        "clone.using_ssh_key",
+        "SSH 키 {} 사용 (core.sshCommand로 저장)",
+    ),
+    ("clone.using_pat", "GitHub 계정 '{}'의 PAT 사용"),
+    (
+        "clone.alias_hint",
+        "~/.ssh/config에 프로필 키를 쓰는 Host 별칭을 추가하면 core.sshCommand 없이 별칭 주소로 복제합니다",
+    ),
+    ("git.worktree_failed", "작업 트리 목록 조회 실패"),
+    ("git.submodules_failed", "하위 모듈 목록 조회 실패"),
+    (
+        "apply.per_worktree",
+        "작업 트리별 설정(config.worktree)에 적용했습니다",
+    ),
+    (
+        "apply.shared_worktrees",
+        "이 저장소의 로컬 설정은 작업 트리 {}개가 함께 쓰므로 모든 작업 트리에 적용됩니다",
+    ),
+    (
+        "apply.per_worktree_hint",
+        "작업 트리마다 다른 프로필을 쓰려면 --per-worktree로 적용하세요",
+    ),
+    ("submodule.none", "초기화된 하위 모듈이 없습니다"),
+    ("submodule.processing", "하위 모듈: {}"),
+    (
+        "submodule.failed",
+        "하위 모듈 {}개에 적용하지 못했습니다 (전체 {}개)",
+    ),
+    ("git.log_failed", "커밋 목록 조회 실패 ({}): {}"),
+    ("git.unknown_revision", "커밋을 찾을 수 없습니다: {}"),
+    ("git.commit_tree_failed", "커밋 생성 실패 (트리 {})"),
+    ("git.update_ref_failed", "{} 참조 갱신 실패"),
+    (
+        "history.operation_in_progress",
+        "rebase, merge 등이 진행 중입니다. 먼저 마치거나 중단하세요",
+    ),
+    (
+        "error.pushed_commits",
+        "다시 쓸 커밋 중 {}개는 이미 push한 커밋입니다",
+    ),
+    (
+        "hint.pushed_commits",
+        "push한 커밋을 다시 쓰면 강제 push가 필요합니다. 확실하면 --force로 실행하세요",
+    ),
+    ("audit.header", "🔍 커밋 신원 점검 (프로필 '{}', {})"),
+    ("audit.range", "범위: {}"),
+    ("audit.unpushed", "push하지 않은 커밋"),
+    ("audit.author", "작성자: {} <{}>"),
+    ("audit.committer", "커미터: {} <{}>"),
+    ("audit.pushed", "(이미 push함)"),
+    (
+        "audit.clean",
+        "모든 커밋이 프로필의 이메일과 일치합니다 ({}개 확인)",
+    ),
+    (
+        "audit.found",
+        "커밋 {}개가 다른 신원으로 작성되었습니다 ({}개 중)",
+    ),
+    (
+        "audit.fix_hint",
+        "'git-switcher fix-authors'로 push하지 않은 커밋을 고칠 수 있습니다",
+    ),
+    (
+        "fix_authors.nothing",
+        "고칠 커밋이 없습니다 (프로필 '{}', {})",
+    ),
+    (
+        "fix_authors.header",
+        "다음 커밋의 작성자/커미터를 {} <{}>(프로필 '{}')로 다시 씁니다:",
+    ),
+    (
+        "fix_authors.pushed",
+        "다시 만들어질 커밋 중 {}개는 이미 push한 커밋입니다",
+    ),
+    ("fix_authors.confirm", "커밋을 다시 쓸까요?"),
+    ("fix_authors.done", "커밋 {}개를 다시 썼습니다"),
+    (
+        "fix_authors.undo_hint",
+        "되돌리려면 'git reset --keep ORIG_HEAD'를 실행하세요",
+    ),
+    ("fix_authors.cancelled", "다시 쓰기를 취소했습니다"),
+    (
+        "fix_authors.signatures_dropped",
+        "서명된 커밋 {}개의 서명이 없어졌습니다 (--sign으로 다시 서명)",
+    ),
+    ("mailmap.header", "다음 이메일을 {} <{}>(으)로 묶습니다:"),
+    (
+        "mailmap.skipped",
+        "{}은(는) 직접 적은 줄이 이미 매핑하고 있어 건너뜁니다",
+    ),
+    ("mailmap.written", "{}을(를) 갱신했습니다"),
+    ("mailmap.unchanged", "{}은(는) 이미 최신 상태입니다"),
+    ("mailmap.dry_run", "--dry-run이므로 저장하지 않았습니다"),
+    ("activity.write_failed", "활동 기록을 남기지 못했습니다: {}"),
+    (
+        "activity.invalid_line",
+        "해석할 수 없는 활동 기록 줄을 건너뜁니다: {}",
+    ),
+    ("log.empty", "조건에 맞는 기록이 없습니다"),
+    ("log.all_accounts", "모든 계정"),
+    ("log.error", "오류: {}"),
+    (
+        "log.invalid_since",
+        "--since 값을 해석할 수 없습니다: {} (7d, 12h, 2026-10-01, 2026-10-01T09:00:00Z 형식)",
+    ),
+    ("exec.running", "{} 실행 (프로필 '{}')"),
+    ("exec.exit_status", "명령이 종료 코드 {}(으)로 끝났습니다"),
+    ("exec.no_command", "실행할 명령이 없습니다"),
+    ("exec.spawn_failed", "{}을(를) 실행할 수 없습니다: {}"),
+    ("cli.import-bundle.on_conflict.skip", "기존 값을 유지"),
+    (
+        "cli.import-bundle.on_conflict.overwrite",
+        "번들의 값으로 덮어씀",
+    ),
+    (
+        "cli.import-bundle.on_conflict.rename",
+        "번들의 프로필을 `<이름>-imported`로 추가 (경로 매핑은 기존 값 유지)",
+    ),
+];
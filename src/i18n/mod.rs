@@ -0,0 +1,147 @@
+mod en;
+mod ko;
+
+use clap::{Command, ValueEnum};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// 메시지 카탈로그 언어
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+pub enum Language {
+    #[default]
+    Ko,
+    En,
+}
+
+impl Language {
+    /// `ko`, `ko_KR.UTF-8`, `en-US` 같은 로캘 문자열 해석
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let code = locale
+            .split(['_', '-', '.', '@'])
+            .next()?
+            .to_ascii_lowercase();
+        match code.as_str() {
+            "" => None,
+            "ko" => Some(Language::Ko),
+            // C/POSIX 등 한국어가 아닌 로캘은 모두 영어로 표시
+            _ => Some(Language::En),
+        }
+    }
+
+    /// 환경 변수 (`LC_ALL` > `LC_MESSAGES` > `LANG`)에서 언어 결정
+    pub fn from_env() -> Option<Self> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Self::from_locale(&value))
+    }
+
+    fn catalog(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::Ko => ko::MESSAGES,
+            Language::En => en::MESSAGES,
+        }
+    }
+}
+
+static LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+/// 프로세스 전체의 표시 언어 설정
+pub fn set_language(language: Language) {
+    LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    match LANGUAGE.load(Ordering::Relaxed) {
+        1 => Language::En,
+        _ => Language::Ko,
+    }
+}
+
+/// 현재 언어의 메시지 (없으면 한국어, 그래도 없으면 키 자체)
+pub fn message(key: &'static str) -> &'static str {
+    lookup(language(), key)
+        .or_else(|| lookup(Language::Ko, key))
+        .unwrap_or(key)
+}
+
+fn lookup(language: Language, key: &str) -> Option<&'static str> {
+    language
+        .catalog()
+        .iter()
+        .find(|(candidate, _)| *candidate == key)
+        .map(|(_, message)| *message)
+}
+
+/// 메시지의 `{}` 자리를 인자로 차례대로 채움
+pub fn format_message(template: &str, args: &[&dyn Display]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut rest = template;
+
+    while let Some(position) = rest.find("{}") {
+        result.push_str(&rest[..position]);
+        match args.next() {
+            Some(arg) => result.push_str(&arg.to_string()),
+            None => result.push_str("{}"),
+        }
+        rest = &rest[position + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 카탈로그 메시지 조회 및 포맷
+///
+/// `t!("key")` 또는 `t!("key", arg1, arg2)` 형태로 사용
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::message($key).to_string()
+    };
+    ($key:expr, $($arg:expr),+ $(,)?) => {
+        $crate::i18n::format_message(
+            $crate::i18n::message($key),
+            &[$(&$arg as &dyn std::fmt::Display),+],
+        )
+    };
+}
+
+/// clap 명령 트리의 설명과 인자 도움말을 카탈로그로 교체
+///
+/// 키는 `cli.<하위 명령 경로>.about`, `cli.<하위 명령 경로>.<인자 ID>` 형식
+pub fn localize_command(command: Command) -> Command {
+    localize(command, "cli")
+}
+
+fn localize(mut command: Command, prefix: &str) -> Command {
+    if let Some(about) = lookup_with_fallback(&format!("{}.about", prefix)) {
+        command = command.about(about);
+    }
+
+    let arg_ids: Vec<String> = command
+        .get_arguments()
+        .map(|arg| arg.get_id().to_string())
+        .collect();
+    for id in arg_ids {
+        if let Some(help) = lookup_with_fallback(&format!("{}.{}", prefix, id)) {
+            command = command.mut_arg(id, |arg| arg.help(help));
+        }
+    }
+
+    let subcommands: Vec<String> = command
+        .get_subcommands()
+        .map(|subcommand| subcommand.get_name().to_string())
+        .collect();
+    for name in subcommands {
+        let sub_prefix = format!("{}.{}", prefix, name);
+        command = command.mut_subcommand(name, |subcommand| localize(subcommand, &sub_prefix));
+    }
+
+    command
+}
+
+fn lookup_with_fallback(key: &str) -> Option<&'static str> {
+    lookup(language(), key).or_else(|| lookup(Language::Ko, key))
+}
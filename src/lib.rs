@@ -1,6 +1,9 @@
 pub mod cli;
 pub mod core;
 pub mod git;
+pub mod i18n;
+pub mod output;
+pub mod report;
 pub mod utils;
 
 pub use core::{Config, Error, Profile, ProfileManager, Result};
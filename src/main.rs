@@ -1,80 +1,722 @@
-use clap::Parser;
+use clap::{CommandFactory, FromArgMatches, ValueEnum};
 use git_switcher::{
-    cli::{Cli, Commands, CredentialAction},
-    core::{Config, Profile, ProfileManager, Result},
+    cli::{Cli, Commands, ConfigAction, CredentialAction, ProfileField},
+    core::{
+        ActivityEvent, ActivityLog, AuditReport, AuthorFix, Bundle, CONFIG_VERSION, Config, Error,
+        MIGRATION_NEEDED_EXIT_CODE, Profile, ProfileManager, Result, Severity, StatusReport,
+        ValidationReport, Verdict,
+        activity::{self, ActivityEntry, ActivityFilter},
+        bundle::{MergeOutcome, MergeReport},
+        config::{self, get_config_path},
+        history,
+        policy::{self, Enforcement, TeamPolicy},
+    },
     git::GitConfig,
-    utils::{auto::AutoDetector, crypto::TokenCrypto, ssh::SshManager},
+    i18n::{self, Language},
+    output::{self, ErrorView, OutputFormat, ProfileView, Verbosity},
+    report, t,
+    utils::{
+        auto::{AutoDetector, SubmoduleResult},
+        clone::CloneManager,
+        crypto::{PASSPHRASE_ITERATIONS, PassphraseKey, TokenCrypto},
+        exec::ExecManager,
+        import::{ImportPlan, ProfileImporter},
+        mailmap::MailmapUpdate,
+        picker::ProfilePicker,
+        ssh::SshManager,
+        wizard::ProfileWizard,
+    },
 };
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
+fn main() {
+    // 도움말과 인자 오류도 번역되도록 파싱 전에 언어를 정함 (설정 파일의 language도 참고)
+    if let Some(path) = early_arg("--config") {
+        config::set_config_path(path.into());
+    }
+    i18n::set_language(detect_language());
+    let matches = i18n::localize_command(Cli::command()).get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    if let Some(path) = cli.config {
+        config::set_config_path(path);
+    }
+    let verbosity = match (cli.quiet, cli.verbose) {
+        (true, _) => Verbosity::Quiet,
+        (_, true) => Verbosity::Verbose,
+        _ => Verbosity::Normal,
+    };
+    report::set_reporter(output::reporter(cli.format, verbosity));
 
-    match cli.command {
-        Commands::Use { profile_name, ssh } => {
-            let config = Config::load()?;
-            let profile = config.get_profile(&profile_name)?;
-            ProfileManager::apply_profile(profile, ssh)?;
+    if let Err(e) = run(cli.command, cli.format) {
+        match cli.format {
+            OutputFormat::Json => {
+                let _ = output::print_json(&json!({ "error": ErrorView::new(&e) }));
+            }
+            OutputFormat::Text => {
+                eprintln!("❌ {}", e);
+                for cause in e.causes() {
+                    eprintln!("   {}", t!("error.caused_by", cause));
+                }
+                if let Some(hint) = e.hint() {
+                    eprintln!("💡 {}", hint);
+                }
+            }
+        }
+        std::process::exit(e.exit_code());
+    }
+}
+
+fn run(command: Commands, format: OutputFormat) -> Result<()> {
+    let json = format == OutputFormat::Json;
+
+    match command {
+        Commands::Use {
+            profile_name,
+            ssh,
+            per_worktree,
+            recurse_submodules,
+        } => {
+            // 동시에 실행된 use/auto가 ~/.git-credentials를 번갈아 덮어쓰지 않도록 잠금
+            let _lock = Config::lock()?;
+            let mut config = Config::load()?;
+            let profile_name = match profile_name {
+                Some(profile_name) => profile_name,
+                None => ProfilePicker::pick(&config)?,
+            };
+            let profile = config.resolve_profile(&profile_name)?;
+            config.check_repo_policy(&profile_name, &profile)?;
+            policy::enforce_for_profile(&config, &profile, ssh)?;
+            if per_worktree {
+                GitConfig::enable_worktree_config()?;
+            }
+            ProfileManager::apply_profile(&profile_name, &profile, ssh)?;
+
+            let submodules = if recurse_submodules {
+                AutoDetector::apply_to_submodules(&mut config, &profile_name, ssh)?
+            } else {
+                Vec::new()
+            };
+            if json {
+                print_applied(&profile_name, &profile, ssh, &submodules)?;
+            }
+            exit_on_submodule_failure(&submodules);
         }
 
         Commands::Show => {
-            ProfileManager::show_current_profile()?;
+            if json {
+                if !GitConfig::is_git_repo() {
+                    return Err(Error::NotGitRepo);
+                }
+                let user_name = GitConfig::get_user_name().ok();
+                let email = GitConfig::get_user_email().ok();
+                let profile = match (&user_name, &email, Config::load()) {
+                    (Some(user_name), Some(email), Ok(config)) => {
+                        config.find_profile_by_identity(user_name, email).cloned()
+                    }
+                    _ => None,
+                };
+                output::print_json(&json!({
+                    "repo_path": GitConfig::get_current_directory()?,
+                    "user_name": user_name,
+                    "email": email,
+                    "profile": profile,
+                }))?;
+            } else {
+                if !GitConfig::is_git_repo() {
+                    return Err(Error::NotGitRepo);
+                }
+                match (GitConfig::get_user_name(), GitConfig::get_user_email()) {
+                    (Ok(name), Ok(email)) => {
+                        println!("{}", t!("show.current_profile"));
+                        println!("  {}", t!("field.name", name));
+                        println!("  {}", t!("field.email", email));
+                    }
+                    _ => println!("{}", t!("show.no_profile")),
+                }
+            }
+        }
+
+        Commands::Status => {
+            let config = Config::load()?;
+            let report = StatusReport::collect(&config)?;
+            if json {
+                output::print_json(&report)?;
+            } else {
+                print_status(&report);
+            }
+            if report.verdict != Verdict::Ok {
+                std::process::exit(report.verdict.exit_code());
+            }
+        }
+
+        Commands::Verify => {
+            if !GitConfig::is_git_repo() {
+                return Err(Error::NotGitRepo);
+            }
+            let config = Config::load()?;
+            let Some(team_policy) = TeamPolicy::load(&config)? else {
+                if json {
+                    output::print_json(&json!({
+                        "policy": null,
+                        "matched_rules": 0,
+                        "violations": [],
+                        "passed": true,
+                    }))?;
+                } else {
+                    println!("{}", t!("verify.no_policy"));
+                    println!("💡 {}", t!("verify.no_policy_hint"));
+                }
+                return Ok(());
+            };
+
+            let remotes = GitConfig::get_remotes()?;
+            let matched = team_policy.matching_rules(&remotes).len();
+            let violations = team_policy.check_repository(&remotes)?;
+            let denied: Vec<String> = violations
+                .iter()
+                .filter(|violation| violation.enforcement == Enforcement::Deny)
+                .map(|violation| {
+                    t!(
+                        "policy.violation",
+                        violation.remote,
+                        violation.rule,
+                        violation.message
+                    )
+                })
+                .collect();
+            let passed = denied.is_empty();
+            // 훅에서 실행한 확인도 어떤 프로필로 통과/실패했는지 남김
+            let profile = match (GitConfig::get_user_name(), GitConfig::get_user_email()) {
+                (Ok(name), Ok(email)) => config.find_profile_by_identity(&name, &email).cloned(),
+                _ => None,
+            };
+            ActivityLog::record(
+                ActivityEvent::Verify,
+                profile.as_deref(),
+                None,
+                (!passed)
+                    .then(|| Error::PolicyViolation(denied.join("; ")))
+                    .as_ref(),
+            );
+
+            if json {
+                output::print_json(&json!({
+                    "policy": team_policy.path,
+                    "matched_rules": matched,
+                    "violations": violations,
+                    "passed": passed,
+                }))?;
+            } else {
+                println!("{}", t!("verify.header", team_policy.path));
+                if matched == 0 {
+                    println!("  {}", t!("verify.no_matching_rules"));
+                }
+                for violation in &violations {
+                    let icon = match violation.enforcement {
+                        Enforcement::Deny => "❌",
+                        Enforcement::Warn => "⚠️",
+                    };
+                    println!(
+                        "  {} {}",
+                        icon,
+                        t!(
+                            "policy.violation",
+                            violation.remote,
+                            violation.rule,
+                            violation.message
+                        )
+                    );
+                }
+                if violations.is_empty() && matched > 0 {
+                    println!("  ✓ {}", t!("verify.passed", matched));
+                }
+            }
+            if !passed {
+                std::process::exit(Error::POLICY_VIOLATION_EXIT_CODE);
+            }
+        }
+
+        Commands::Audit { range, profile } => {
+            let config = Config::load()?;
+            let report = AuditReport::collect(&config, range.as_deref(), profile)?;
+            if json {
+                output::print_json(&report)?;
+            } else {
+                print_audit(&report);
+            }
+            if report.exit_code() != 0 {
+                std::process::exit(report.exit_code());
+            }
+        }
+
+        Commands::FixAuthors {
+            base,
+            profile,
+            sign,
+            force,
+            yes,
+            dry_run,
+        } => {
+            let config = Config::load()?;
+            let plan = AuthorFix::plan(&config, base.as_deref(), profile)?;
+
+            if !json {
+                print_author_fix(&plan);
+            }
+            let rewritten = if plan.mismatched.is_empty() || dry_run {
+                Vec::new()
+            } else {
+                plan.check_pushed(force)?;
+                if !yes && !std::io::stdin().is_terminal() {
+                    return Err(Error::NotInteractive);
+                }
+                if yes || ProfileWizard::confirm(&t!("fix_authors.confirm"), false)? {
+                    let rewritten = plan.apply(sign, force)?;
+                    report!(Success, "{}", t!("fix_authors.done", rewritten.len()));
+                    report!(Hint, "{}", t!("fix_authors.undo_hint"));
+                    rewritten
+                } else {
+                    report!(Info, "{}", t!("fix_authors.cancelled"));
+                    Vec::new()
+                }
+            };
+
+            if json {
+                output::print_json(&json!({
+                    "action": "fix_authors",
+                    "profile_name": plan.profile_name,
+                    "base": plan.base,
+                    "mismatched": plan.mismatched,
+                    "pushed": plan.pushed,
+                    "rewritten": rewritten,
+                    "written": !rewritten.is_empty(),
+                }))?;
+            }
+        }
+
+        Commands::Log {
+            repo,
+            profile,
+            since,
+        } => {
+            let filter = ActivityFilter {
+                repo: repo
+                    .map(|path| activity::resolve_repo_filter(path.as_deref()))
+                    .transpose()?,
+                profile,
+                since: since.as_deref().map(activity::parse_since).transpose()?,
+            };
+            let path = ActivityLog::path()?;
+            let entries = ActivityLog::read(&filter)?;
+            if json {
+                output::print_json(&json!({
+                    "file": path,
+                    "entries": entries,
+                }))?;
+            } else {
+                print_activity(&entries);
+            }
+        }
+
+        Commands::Which { path } => {
+            let config = Config::load()?;
+            let path = match path {
+                Some(path) => path,
+                None => GitConfig::get_current_directory()?,
+            };
+            let mapping = config.find_mapping_for_path(&path);
+
+            if json {
+                output::print_json(&json!({
+                    "path": path,
+                    "profile": mapping.map(|(_, profile)| profile),
+                    "mapping": mapping.map(|(mapped_path, _)| mapped_path),
+                }))?;
+            } else {
+                match mapping {
+                    Some((mapped_path, profile)) => {
+                        println!("{}", t!("which.mapped", profile, mapped_path, profile))
+                    }
+                    None => println!("{}", t!("which.not_mapped", path)),
+                }
+            }
         }
 
         Commands::List => {
             let config = Config::load()?;
+            let names: Vec<&String> = config.profiles.keys().collect();
+            let mappings: Vec<(&String, &String)> = config.path_mappings.iter().collect();
+
+            if json {
+                let profiles: Vec<_> = names
+                    .iter()
+                    .map(|name| match config.resolve_profile(name) {
+                        Ok(profile) => json!({
+                            "name": name,
+                            "profile": ProfileView::new(&profile),
+                        }),
+                        Err(e) => json!({ "name": name, "error": ErrorView::new(&e) }),
+                    })
+                    .collect();
+                let path_mappings: Vec<_> = mappings
+                    .iter()
+                    .map(|(path, profile)| json!({ "path": path, "profile": profile }))
+                    .collect();
+                output::print_json(&json!({
+                    "profiles": profiles,
+                    "path_mappings": path_mappings,
+                    "remote_mappings": config
+                        .remote_mappings
+                        .iter()
+                        .map(|(pattern, profile)| json!({ "pattern": pattern, "profile": profile }))
+                        .collect::<Vec<_>>(),
+                }))?;
+                return Ok(());
+            }
 
             if config.profiles.is_empty() {
-                println!("등록된 프로필이 없습니다.");
+                println!("{}", t!("list.empty"));
             } else {
-                println!("사용 가능한 프로필:");
-                for (name, profile) in &config.profiles {
-                    print!("  {}: {} <{}>", name, profile.name, profile.email);
-                    if profile.ssh_key.is_some() {
-                        print!(" [SSH]");
-                    }
-                    if profile.has_pat() {
-                        if let Some(masked) = profile.get_masked_pat() {
-                            print!(" [PAT: {}]", masked);
+                println!("{}", t!("picker.available"));
+                for name in names {
+                    let profile = match config.resolve_profile(name) {
+                        Ok(profile) => profile,
+                        Err(e) => {
+                            println!("  {}: ❌ {}", name, e);
+                            continue;
                         }
-                    }
-                    println!();
+                    };
+                    println!("  {}: {}", name, profile.summary());
                 }
             }
 
-            if !config.path_mappings.is_empty() {
-                println!("\n경로 매핑:");
-                for (path, profile) in &config.path_mappings {
+            if !mappings.is_empty() {
+                println!("\n{}", t!("list.mappings"));
+                for (path, profile) in mappings {
                     println!("  {} -> {}", path, profile);
                 }
             }
+
+            if !config.remote_mappings.is_empty() {
+                println!("\n{}", t!("list.remote_mappings"));
+                for (pattern, profile) in &config.remote_mappings {
+                    println!("  {} -> {}", pattern, profile);
+                }
+            }
         }
 
-        Commands::Init => {
-            match Config::init_default() {
-                Ok(_) => {
-                    let config_path = git_switcher::core::config::get_config_path()?;
-                    println!("✓ 설정 파일이 생성되었습니다: {}", config_path.display());
-                    println!("설정 파일을 편집하여 프로필을 수정하세요.");
+        Commands::ShowProfile { name, resolved } => {
+            let config = Config::load()?;
 
-                    // SSH 설정 예시 출력
-                    let config = Config::load()?;
-                    if config.profiles.values().any(|p| p.ssh_key.is_some()) {
-                        println!("\n🔧 SSH 설정 예시:");
-                        println!(
-                            "{}",
-                            SshManager::generate_ssh_config_example(&config.profiles)
-                        );
-                    }
+            if json {
+                let (profile, origins) = if resolved {
+                    let resolved = config.resolve_profile_with_origins(&name)?;
+                    let origins: BTreeMap<String, String> = resolved
+                        .origins
+                        .iter()
+                        .map(|(field, source)| (field.clone(), source.to_string()))
+                        .collect();
+                    (resolved.profile, Some(origins))
+                } else {
+                    (config.get_profile(&name)?.clone(), None)
+                };
+                output::print_json(&json!({
+                    "name": name,
+                    "profile": ProfileView::new(&profile),
+                    "origins": origins,
+                }))?;
+            } else if resolved {
+                let resolved = config.resolve_profile_with_origins(&name)?;
+                println!("{}", t!("show_profile.resolved_header", name));
+                print_profile_fields(&resolved.profile, |field| {
+                    resolved.origins.get(field).map(|source| source.to_string())
+                });
+            } else {
+                let profile = config.get_profile(&name)?;
+                println!("{}", t!("show_profile.header", name));
+                print_profile_fields(profile, |_| None);
+            }
+        }
+
+        Commands::Init { interactive: true } => {
+            // 입력을 기다리는 동안 다른 명령이 막히지 않도록 모두 입력받은 뒤에 잠금
+            let mut taken: Vec<String> = Config::load_or_default()?.profiles.into_keys().collect();
+            let mut answers = Vec::new();
+            loop {
+                let answer = ProfileWizard::run(&taken)?;
+                taken.push(answer.name.clone());
+                answers.push(answer);
+                if !ProfileWizard::confirm(&t!("init.add_another"), false)? {
+                    break;
+                }
+            }
+
+            let _lock = Config::lock()?;
+            let mut config = Config::load_or_default()?;
+            for answer in answers {
+                let name = answer.apply(&mut config)?;
+                report!(Success, "{}", t!("profile.added", name));
+            }
+            config.save()?;
+
+            let config_path = get_config_path()?;
+            report!(Success, "{}", t!("init.saved", config_path.display()));
+            if json {
+                output::print_json(&json!({ "action": "init", "config_path": config_path }))?;
+            }
+        }
+
+        Commands::Init { interactive: false } => {
+            let _lock = Config::lock()?;
+            let config = Config::init_default()?;
+            let config_path = get_config_path()?;
+            report!(Success, "{}", t!("init.created", config_path.display()));
+            report!(Info, "{}", t!("init.edit_hint"));
+
+            // SSH 설정 예시 출력
+            if config.profiles.values().any(|p| p.ssh_key.is_some()) {
+                report!(Info);
+                report!(Info, "{}", t!("init.ssh_example"));
+                report!(
+                    Info,
+                    "{}",
+                    SshManager::generate_ssh_config_example(&config.profiles)
+                );
+            }
+
+            if json {
+                output::print_json(&json!({ "action": "init", "config_path": config_path }))?;
+            }
+        }
+
+        Commands::Import {
+            roots,
+            yes,
+            dry_run,
+        } => {
+            let _lock = Config::lock()?;
+            let mut config = Config::load_or_default()?;
+            let plan = ProfileImporter::scan(&config, &roots)?;
+
+            if !json {
+                print_import_plan(&plan);
+            }
+            let written = if plan.is_empty() || dry_run {
+                false
+            } else if !yes && !std::io::stdin().is_terminal() {
+                return Err(Error::NotInteractive);
+            } else if yes || ProfileWizard::confirm(&t!("import.confirm"), true)? {
+                plan.apply(&mut config);
+                config.save()?;
+                report!(
+                    Success,
+                    "{}",
+                    t!(
+                        "import.saved",
+                        plan.profiles.len(),
+                        plan.path_mappings.len()
+                    )
+                );
+                true
+            } else {
+                report!(Info, "{}", t!("import.cancelled"));
+                false
+            };
+
+            if json {
+                output::print_json(&json!({
+                    "action": "import",
+                    "profiles": plan.profiles,
+                    "path_mappings": plan.path_mappings,
+                    "skipped": plan.skipped,
+                    "written": written,
+                }))?;
+            }
+        }
+
+        Commands::Clone {
+            url,
+            dir,
+            profile,
+            ssh,
+        } => {
+            // 복제 후 프로필을 적용하면서 ~/.git-credentials를 바꾸므로 use와 같이 잠금
+            let _lock = Config::lock()?;
+            let mut config = Config::load()?;
+            let plan = CloneManager::plan(&config, &url, dir.as_deref(), profile, ssh)?;
+            CloneManager::run(&mut config, &plan)?;
+
+            if json {
+                output::print_json(&json!({
+                    "action": "clone",
+                    "url": plan.url,
+                    "clone_url": plan.clone_url,
+                    "directory": plan.directory,
+                    "profile_name": plan.profile_name,
+                    "source": plan.source,
+                    "transport": plan.transport,
+                }))?;
+            }
+        }
+
+        Commands::Exec { profile, command } => {
+            let config = Config::load()?;
+            // 명령의 출력과 섞이지 않도록 결과는 종료 코드로만 전달
+            let code = ExecManager::run(&config, &profile, &command)?;
+            if code != 0 {
+                std::process::exit(code);
+            }
+        }
+
+        Commands::Mailmap {
+            profiles,
+            canonical,
+            file,
+            dry_run,
+        } => {
+            let config = Config::load()?;
+            let canonical = match canonical {
+                Some(canonical) => canonical,
+                None => {
+                    let current_path = GitConfig::get_current_directory()?;
+                    config
+                        .find_profile_for_path(&current_path)
+                        .cloned()
+                        .ok_or(Error::NoMappedProfile(current_path))?
                 }
-                Err(e) => {
-                    eprintln!("❌ 초기화 실패: {}", e);
-                    std::process::exit(1);
+            };
+            let update = MailmapUpdate::plan(&config, file.as_deref(), &canonical, &profiles)?;
+            let written = update.changed && !dry_run;
+            if written {
+                update.write()?;
+            }
+
+            if json {
+                output::print_json(&json!({
+                    "action": "mailmap",
+                    "file": update.path,
+                    "canonical": {
+                        "profile": canonical,
+                        "name": update.canonical_name,
+                        "email": update.canonical_email,
+                    },
+                    "entries": update.entries,
+                    "skipped": update.skipped,
+                    "changed": update.changed,
+                    "written": written,
+                }))?;
+            } else {
+                print_mailmap(&update);
+                if written {
+                    report!(Success, "{}", t!("mailmap.written", update.path.display()));
+                } else if update.changed {
+                    report!(Info, "{}", t!("mailmap.dry_run"));
+                } else {
+                    report!(Info, "{}", t!("mailmap.unchanged", update.path.display()));
                 }
             }
         }
 
-        Commands::Auto { ssh } => {
-            AutoDetector::detect_and_apply_profile(ssh)?;
+        Commands::Export {
+            file,
+            profiles,
+            include_tokens,
+        } => {
+            let config = Config::load()?;
+            let bundle = if include_tokens {
+                let passphrase = ProfileWizard::prompt_passphrase(true)?;
+                let salt = PassphraseKey::generate_salt();
+                let key = PassphraseKey::derive(&passphrase, &salt, PASSPHRASE_ITERATIONS);
+                Bundle::from_config(&config, &profiles, Some((&key, &salt)))?
+            } else {
+                Bundle::from_config(&config, &profiles, None)?
+            };
+            bundle.save(&file)?;
+
+            report!(
+                Success,
+                "{}",
+                t!(
+                    "bundle.exported",
+                    file.display(),
+                    bundle.profiles.len(),
+                    bundle.path_mappings.len(),
+                    bundle.token_count()
+                )
+            );
+            if json {
+                output::print_json(&json!({
+                    "action": "export",
+                    "file": file,
+                    "profiles": bundle.profiles.keys().collect::<Vec<_>>(),
+                    "path_mappings": bundle.path_mappings.len(),
+                    "tokens": bundle.token_count(),
+                }))?;
+            }
+        }
+
+        Commands::ImportBundle {
+            file,
+            on_conflict,
+            skip_tokens,
+            dry_run,
+        } => {
+            let _lock = Config::lock()?;
+            let mut config = Config::load_or_default()?;
+            let bundle = Bundle::load(&file)?;
+            let key = if bundle.token_count() > 0 && !skip_tokens {
+                Some(bundle.unlock(&ProfileWizard::prompt_passphrase(false)?)?)
+            } else {
+                None
+            };
+
+            let merge = bundle.merge_into(&mut config, on_conflict, key.as_ref())?;
+            let written = merge.has_changes() && !dry_run;
+            if written {
+                config.save()?;
+            }
+
+            if json {
+                output::print_json(&json!({
+                    "action": "import_bundle",
+                    "file": file,
+                    "profiles": merge.profiles,
+                    "path_mappings": merge.path_mappings,
+                    "tokens": merge.tokens,
+                    "tokens_skipped": merge.tokens_skipped,
+                    "written": written,
+                }))?;
+            } else {
+                print_merge_report(&merge);
+                if written {
+                    report!(Success, "{}", t!("bundle.imported", file.display()));
+                } else if dry_run {
+                    report!(Info, "{}", t!("bundle.dry_run"));
+                }
+            }
+        }
+
+        Commands::Auto {
+            ssh,
+            per_worktree,
+            recurse_submodules,
+        } => {
+            let _lock = Config::lock()?;
+            let (profile_name, profile) =
+                AutoDetector::detect_and_apply_profile(ssh, per_worktree)?;
+
+            let submodules = if recurse_submodules {
+                let mut config = Config::load()?;
+                AutoDetector::apply_to_submodules(&mut config, &profile_name, ssh)?
+            } else {
+                Vec::new()
+            };
+            if json {
+                print_applied(&profile_name, &profile, ssh, &submodules)?;
+            }
+            exit_on_submodule_failure(&submodules);
         }
 
         Commands::Add {
@@ -85,51 +727,52 @@ fn main() -> Result<()> {
             github_pat,
             signing_key,
             ssh_key,
+            force,
+            interactive: false,
         } => {
-            let mut config = Config::load().unwrap_or_default();
+            // --interactive가 없으면 clap이 필수 인자로 검증함
+            let (Some(name), Some(user_name), Some(email)) = (name, user_name, email) else {
+                unreachable!("name, user_name, email are required without --interactive");
+            };
+            let _lock = Config::lock()?;
+            let mut config = Config::load_or_default()?;
+
+            if config.profiles.contains_key(&name) && !force {
+                return Err(Error::ProfileExists(name));
+            }
 
             let mut profile = Profile {
+                extends: None,
                 name: user_name,
                 email,
                 signingkey: signing_key,
                 ssh_key,
                 github_username: github_username.clone(),
                 encrypted_pat: None,
+                git_config: BTreeMap::new(),
             };
 
             // GitHub PAT 처리
             if let Some(pat) = github_pat {
-                // PAT 유효성 검증
-                if !TokenCrypto::validate_github_pat(&pat) {
-                    println!("⚠️  경고: 입력된 토큰이 올바른 GitHub PAT 형식이 아닐 수 있습니다.");
-                }
-
-                // PAT를 암호화해서 저장
-                match profile.set_encrypted_pat(&pat) {
-                    Ok(_) => {
-                        println!(
-                            "🔑 GitHub PAT가 암호화되어 저장되었습니다: {}",
-                            TokenCrypto::mask_token(&pat)
-                        );
-                    }
-                    Err(e) => {
-                        eprintln!("❌ PAT 암호화 실패: {}", e);
-                        std::process::exit(1);
-                    }
-                }
+                set_profile_pat(&mut profile, &pat)?;
 
                 // GitHub 사용자명이 없으면 PAT 검증을 통해 가져오기 시도
                 if github_username.is_none() {
-                    println!("💡 GitHub API를 통해 사용자명 확인 중...");
-                    if let Ok(detected_username) = detect_github_username_from_pat(&pat) {
+                    report!(Hint, "{}", t!("add.detecting_username"));
+                    if let Ok(detected_username) = GitConfig::detect_github_username(&pat) {
                         profile.github_username = Some(detected_username.clone());
-                        println!("✓ GitHub 사용자명 자동 감지: {}", detected_username);
+                        report!(
+                            Success,
+                            "{}",
+                            t!("pat.username_detected", detected_username)
+                        );
                     }
                 }
             } else if github_username.is_some() {
-                println!("💡 GitHub PAT를 나중에 추가하려면:");
-                println!(
-                    "  git-switcher add {} --github-pat <YOUR_PAT> (기존 프로필 업데이트)",
+                report!(Hint, "{}", t!("add.pat_later"));
+                report!(
+                    Info(1),
+                    "git-switcher edit {} --github-pat <YOUR_PAT>",
                     name
                 );
             }
@@ -137,47 +780,200 @@ fn main() -> Result<()> {
             config.add_profile(name.clone(), profile);
             config.save()?;
 
-            println!("✓ 프로필 '{}'이 추가되었습니다.", name);
+            report!(Success, "{}", t!("profile.added", name));
+            if json {
+                output::print_json(&json!({ "action": "add", "profile": name }))?;
+            }
+        }
+
+        Commands::Add {
+            interactive: true, ..
+        } => {
+            // 입력을 기다리는 동안 다른 명령이 막히지 않도록 입력받은 뒤에 잠금
+            let taken: Vec<String> = Config::load_or_default()?.profiles.into_keys().collect();
+            let answer = ProfileWizard::run(&taken)?;
+
+            let _lock = Config::lock()?;
+            let mut config = Config::load_or_default()?;
+            let name = answer.apply(&mut config)?;
+            config.save()?;
+
+            report!(Success, "{}", t!("profile.added", name));
+            if json {
+                output::print_json(&json!({ "action": "add", "profile": name }))?;
+            }
+        }
+
+        Commands::Edit {
+            name,
+            user_name,
+            email,
+            github_username,
+            github_pat,
+            signing_key,
+            ssh_key,
+            extends,
+            unset,
+        } => {
+            let _lock = Config::lock()?;
+            let mut config = Config::load()?;
+            let profile = config.get_profile_mut(&name)?;
+
+            for field in unset {
+                match field {
+                    ProfileField::UserName => profile.name.clear(),
+                    ProfileField::Email => profile.email.clear(),
+                    ProfileField::GithubUsername => profile.github_username = None,
+                    ProfileField::GithubPat => profile.encrypted_pat = None,
+                    ProfileField::SigningKey => profile.signingkey = None,
+                    ProfileField::SshKey => profile.ssh_key = None,
+                    ProfileField::Extends => profile.extends = None,
+                }
+            }
+
+            if let Some(user_name) = user_name {
+                profile.name = user_name;
+            }
+            if let Some(email) = email {
+                profile.email = email;
+            }
+            if let Some(github_username) = github_username {
+                profile.github_username = Some(github_username);
+            }
+            if let Some(signing_key) = signing_key {
+                profile.signingkey = Some(signing_key);
+            }
+            if let Some(ssh_key) = ssh_key {
+                profile.ssh_key = Some(ssh_key);
+            }
+            if let Some(extends) = extends {
+                profile.extends = Some(extends);
+            }
+            if let Some(pat) = github_pat {
+                set_profile_pat(profile, &pat)?;
+            }
+
+            // 상속 대상이 없거나 필수 값이 빠진 상태로는 저장하지 않음
+            config.resolve_profile(&name)?;
+            config.save()?;
+
+            report!(Success, "{}", t!("profile.edited", name));
+            if json {
+                output::print_json(&json!({ "action": "edit", "profile": name }))?;
+            }
         }
 
-        Commands::Remove { name } => {
+        Commands::Rename { old_name, new_name } => {
+            let _lock = Config::lock()?;
             let mut config = Config::load()?;
-            config.remove_profile(&name)?;
+            let updated_mappings = config.rename_profile(&old_name, &new_name)?;
             config.save()?;
 
-            println!("✓ 프로필 '{}'이 제거되었습니다.", name);
+            report!(Success, "{}", t!("profile.renamed", old_name, new_name));
+            for path in &updated_mappings {
+                report!(Info(1), "{}", t!("profile.mapping_updated", path, new_name));
+            }
+            if json {
+                output::print_json(&json!({
+                    "action": "rename",
+                    "old_name": old_name,
+                    "new_name": new_name,
+                    "updated_mappings": updated_mappings,
+                }))?;
+            }
         }
 
-        Commands::Map { path, profile } => {
+        Commands::Remove { name, cascade } => {
+            let _lock = Config::lock()?;
+            let mut config = Config::load()?;
+            let removed_mappings = config.remove_profile(&name, cascade)?;
+            config.save()?;
+
+            report!(Success, "{}", t!("profile.removed", name));
+            for path in &removed_mappings {
+                report!(Info(1), "{}", t!("profile.mapping_removed", path));
+            }
+            if json {
+                output::print_json(&json!({
+                    "action": "remove",
+                    "profile": name,
+                    "removed_mappings": removed_mappings,
+                }))?;
+            }
+        }
+
+        Commands::Map {
+            path,
+            profile,
+            remote,
+        } => {
+            let _lock = Config::lock()?;
             let mut config = Config::load()?;
 
             // 프로필이 존재하는지 확인
             config.get_profile(&profile)?;
 
-            config.add_path_mapping(path.clone(), profile.clone());
+            if remote {
+                config.add_remote_mapping(path.clone(), profile.clone());
+            } else {
+                config.add_path_mapping(path.clone(), profile.clone());
+            }
             config.save()?;
 
-            println!("✓ 경로 매핑이 추가되었습니다: {} -> {}", path, profile);
+            report!(Success, "{}", t!("map.added", path, profile));
+            if json {
+                output::print_json(&json!({
+                    "action": "map",
+                    "path": path,
+                    "profile": profile,
+                    "remote": remote,
+                }))?;
+            }
         }
 
-        Commands::Unmap { path } => {
+        Commands::Unmap { path, remote } => {
+            let _lock = Config::lock()?;
             let mut config = Config::load()?;
+            let table = if remote {
+                "remote_mappings"
+            } else {
+                "path_mappings"
+            };
+            config.ensure_user_value(&[table, &path])?;
 
-            if config.remove_path_mapping(&path) {
+            let removed = if remote {
+                config.remove_remote_mapping(&path)
+            } else {
+                config.remove_path_mapping(&path)
+            };
+            if removed {
                 config.save()?;
-                println!("✓ 경로 매핑이 제거되었습니다: {}", path);
+                report!(Success, "{}", t!("map.removed", path));
             } else {
-                println!("❌ 해당 경로의 매핑을 찾을 수 없습니다: {}", path);
+                report!(Failure, "{}", t!("map.not_found", path));
+            }
+            if json {
+                output::print_json(&json!({
+                    "action": "unmap",
+                    "path": path,
+                    "remote": remote,
+                    "removed": removed,
+                }))?;
             }
         }
 
         Commands::Credentials { action } => match action {
+            CredentialAction::List if json => {
+                let accounts = GitConfig::get_cached_github_accounts()?;
+                output::print_json(&json!({ "accounts": accounts }))?;
+            }
+
             CredentialAction::List => {
-                println!("🔍 캐시된 GitHub 계정들:");
+                println!("🔍 {}", t!("credentials.cached_accounts"));
                 match GitConfig::get_cached_github_accounts() {
                     Ok(accounts) => {
                         if accounts.is_empty() {
-                            println!("  (캐시된 계정이 없습니다)");
+                            println!("  {}", t!("credentials.no_cached_accounts"));
                         } else {
                             for account in accounts {
                                 println!("  - {}", account);
@@ -185,22 +981,130 @@ fn main() -> Result<()> {
                         }
                     }
                     Err(e) => {
-                        println!("  ❌ 계정 목록 조회 실패: {}", e);
+                        println!("  ❌ {}", t!("credentials.list_failed", format!("{:#}", e)));
                     }
                 }
             }
 
             CredentialAction::Clear { username } => {
-                println!("🔧 계정 '{}' 크리덴셜 삭제 중...", username);
-                GitConfig::clear_github_credentials(&username)?;
-                let _ = GitConfig::erase_credentials_for_host("github.com", &username);
-                println!("✓ 계정 '{}' 크리덴셜이 삭제되었습니다.", username);
+                report!(Step, "{}", t!("credentials.clearing", username));
+                let cleared = GitConfig::clear_github_credentials(&username)
+                    .and_then(|_| GitConfig::erase_credentials_for_host("github.com", &username));
+                ActivityLog::record(
+                    ActivityEvent::CredentialErase,
+                    None,
+                    Some(&username),
+                    cleared.as_ref().err(),
+                );
+                cleared?;
+                report!(Success, "{}", t!("credentials.cleared", username));
+                if json {
+                    output::print_json(&json!({
+                        "action": "credentials_clear",
+                        "username": username,
+                    }))?;
+                }
             }
 
             CredentialAction::ClearAll => {
-                println!("🔧 모든 GitHub 계정 크리덴셜 삭제 중...");
-                GitConfig::clear_all_github_credentials()?;
-                println!("✓ 모든 GitHub 계정 크리덴셜이 삭제되었습니다.");
+                report!(Step, "{}", t!("credentials.clearing_all"));
+                let cleared = GitConfig::clear_all_github_credentials();
+                ActivityLog::record(
+                    ActivityEvent::CredentialErase,
+                    None,
+                    None,
+                    cleared.as_ref().err(),
+                );
+                cleared?;
+                report!(Success, "{}", t!("credentials.cleared_all"));
+                if json {
+                    output::print_json(&json!({ "action": "credentials_clear_all" }))?;
+                }
+            }
+        },
+
+        Commands::Config { action } => match action {
+            ConfigAction::Migrate { check: true } => {
+                let version = Config::file_version()?;
+                let needed = version < CONFIG_VERSION;
+                if json {
+                    output::print_json(&json!({
+                        "action": "config_migrate_check",
+                        "version": version,
+                        "current_version": CONFIG_VERSION,
+                        "migration_needed": needed,
+                    }))?;
+                } else if needed {
+                    println!(
+                        "⚠️  {}",
+                        t!("config.migration_needed", version, CONFIG_VERSION)
+                    );
+                } else {
+                    println!("✓ {}", t!("config.up_to_date", version));
+                }
+                if needed {
+                    std::process::exit(MIGRATION_NEEDED_EXIT_CODE);
+                }
+            }
+
+            ConfigAction::Validate { fix } => {
+                // 고칠 때만 잠그고 저장
+                let _lock = if fix { Some(Config::lock()?) } else { None };
+                let mut config = Config::load()?;
+                let path = get_config_path()?;
+                let content = std::fs::read_to_string(&path)?;
+                let mut report = ValidationReport::collect(&config, &path, &content);
+                if fix && report.apply_fixes(&mut config) > 0 {
+                    config.save()?;
+                }
+
+                if json {
+                    output::print_json(&report)?;
+                } else {
+                    print_validation(&report);
+                }
+                if report.exit_code() != 0 {
+                    std::process::exit(report.exit_code());
+                }
+            }
+
+            ConfigAction::Show => {
+                let config = Config::load()?;
+                let layers = config.layer_infos()?;
+                let settings = config.settings()?;
+                if json {
+                    output::print_json(&json!({ "layers": layers, "settings": settings }))?;
+                } else {
+                    println!("{}", t!("layers.files"));
+                    for info in &layers {
+                        let path = info.path.clone().unwrap_or_else(|| "-".to_string());
+                        if info.loaded {
+                            println!("  {:<6} {}", info.layer, path);
+                        } else {
+                            println!("  {:<6} {} ({})", info.layer, path, t!("layers.missing"));
+                        }
+                    }
+                    println!();
+                    println!("{}", t!("layers.settings"));
+                    for setting in &settings {
+                        println!("  [{}] {} = {}", setting.layer, setting.key, setting.value);
+                    }
+                }
+            }
+
+            ConfigAction::Migrate { check: false } => {
+                let migration = Config::migrate()?;
+                if migration.is_none() {
+                    report!(Success, "{}", t!("config.up_to_date", CONFIG_VERSION));
+                }
+                if json {
+                    output::print_json(&json!({
+                        "action": "config_migrate",
+                        "from": migration.as_ref().map(|m| m.from),
+                        "to": CONFIG_VERSION,
+                        "backup": migration.as_ref().map(|m| &m.backup),
+                    }))?;
+                }
             }
         },
     }
@@ -208,39 +1112,406 @@ fn main() -> Result<()> {
     Ok(())
 }
 
-/// GitHub API를 통해 PAT에서 사용자명 추출
-fn detect_github_username_from_pat(pat: &str) -> Result<String> {
-    use std::process::Command;
-
-    let output = Command::new("curl")
-        .args(&[
-            "-s",
-            "-H",
-            &format!("Authorization: token {}", pat),
-            "-H",
-            "User-Agent: git-switcher",
-            "https://api.github.com/user",
-        ])
-        .output();
-
-    match output {
-        Ok(result) => {
-            if result.status.success() {
-                let response = String::from_utf8_lossy(&result.stdout);
-                // JSON에서 login 필드 추출 (간단한 파싱)
-                if let Some(start) = response.find("\"login\":\"") {
-                    let start = start + 9;
-                    if let Some(end) = response[start..].find("\"") {
-                        return Ok(response[start..start + end].to_string());
-                    }
-                }
+/// 표시 언어 결정 (`--lang` > 설정 파일의 `language` > 로캘 환경 변수 > 한국어)
+fn detect_language() -> Language {
+    if let Some(language) =
+        early_arg("--lang").and_then(|value| Language::from_str(&value, true).ok())
+    {
+        return language;
+    }
+
+    Config::language_setting()
+        .and_then(|language| Language::from_locale(&language))
+        .or_else(Language::from_env)
+        .unwrap_or_default()
+}
+
+/// clap으로 파싱하기 전에 필요한 전역 옵션 값 (`--name value` 또는 `--name=value`)
+fn early_arg(name: &str) -> Option<String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.strip_prefix(name) {
+            Some("") => return args.next(),
+            Some(rest) if rest.starts_with('=') => return Some(rest[1..].to_string()),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// 프로필 적용 결과를 JSON으로 출력
+fn print_applied(
+    profile_name: &str,
+    profile: &Profile,
+    ssh: bool,
+    submodules: &[SubmoduleResult],
+) -> Result<()> {
+    output::print_json(&json!({
+        "action": "apply",
+        "repo_path": GitConfig::get_current_directory()?,
+        "profile_name": profile_name,
+        "ssh": ssh,
+        "worktree": GitConfig::worktree_state()?,
+        "profile": ProfileView::new(profile),
+        "submodules": submodules.iter().map(|submodule| json!({
+            "path": submodule.path,
+            "profile": submodule.profile,
+            "error": submodule.error.as_ref().map(ErrorView::new),
+        })).collect::<Vec<_>>(),
+    }))
+}
+
+/// 하위 모듈 중 하나라도 실패했으면 결과를 출력한 뒤 첫 번째 실패의 종료 코드로 끝냄
+fn exit_on_submodule_failure(submodules: &[SubmoduleResult]) {
+    let failed: Vec<&Error> = submodules
+        .iter()
+        .filter_map(|submodule| submodule.error.as_ref())
+        .collect();
+    if let Some(first) = failed.first() {
+        report!(
+            Failure,
+            "{}",
+            t!("submodule.failed", failed.len(), submodules.len())
+        );
+        std::process::exit(first.exit_code());
+    }
+}
+
+/// PAT 형식을 확인하고 암호화해서 프로필에 저장
+fn set_profile_pat(profile: &mut Profile, pat: &str) -> Result<()> {
+    if !TokenCrypto::validate_github_pat(pat) {
+        report!(Warning, "{}", t!("pat.format_warning"));
+    }
+
+    profile.set_encrypted_pat(pat)?;
+    report!(
+        Success,
+        "{}",
+        t!("pat.stored", TokenCrypto::mask_token(pat))
+    );
+    Ok(())
+}
+
+/// 설정 검사 결과를 `파일:줄:열` 형식으로 출력
+fn print_validation(report: &ValidationReport) {
+    for issue in &report.issues {
+        let icon = match issue.severity {
+            Severity::Error => "❌",
+            Severity::Warning => "⚠️ ",
+        };
+        let location = issue
+            .location
+            .map(|location| format!("{}:{}:{}", report.path, location.line, location.column))
+            .unwrap_or_else(|| report.path.clone());
+        let mut line = format!(
+            "{} {}: {}[{}]: {}",
+            icon, location, issue.severity, issue.code, issue.message
+        );
+        if issue.fixed {
+            line.push_str(&format!(" ({})", t!("validate.fixed")));
+        }
+        println!("{}", line);
+    }
+
+    let (errors, warnings) = (
+        report.count(Severity::Error),
+        report.count(Severity::Warning),
+    );
+    if errors == 0 && warnings == 0 {
+        println!("✓ {}", t!("validate.clean"));
+    } else {
+        println!("{}", t!("validate.summary", errors, warnings));
+    }
+    if report.fixable() > 0 {
+        println!("💡 {}", t!("validate.fixable", report.fixable()));
+    }
+}
+
+/// `audit` 결과 출력
+fn print_audit(report: &AuditReport) {
+    println!(
+        "{}",
+        t!("audit.header", report.profile_name, report.expected_email)
+    );
+    let range = report.range.clone().unwrap_or_else(|| t!("audit.unpushed"));
+    println!("  {}", t!("audit.range", range));
+    println!();
+
+    for mismatched in &report.commits {
+        let commit = &mismatched.commit;
+        println!("  {} {}", history::short(&commit.commit), commit.subject);
+        if mismatched.mismatched.contains(&"author") {
+            println!(
+                "    {}",
+                t!("audit.author", commit.author_name, commit.author_email)
+            );
+        }
+        if mismatched.mismatched.contains(&"committer") {
+            println!(
+                "    {}",
+                t!(
+                    "audit.committer",
+                    commit.committer_name,
+                    commit.committer_email
+                )
+            );
+        }
+        if mismatched.pushed {
+            println!("    {}", t!("audit.pushed"));
+        }
+    }
+
+    if report.commits.is_empty() {
+        println!("✓ {}", t!("audit.clean", report.checked));
+    } else {
+        println!();
+        println!(
+            "❌ {}",
+            t!("audit.found", report.commits.len(), report.checked)
+        );
+        if report.commits.iter().any(|commit| !commit.pushed) {
+            println!("💡 {}", t!("audit.fix_hint"));
+        }
+    }
+}
+
+/// `fix-authors`가 다시 쓸 커밋 출력
+fn print_author_fix(plan: &AuthorFix) {
+    if plan.mismatched.is_empty() {
+        println!(
+            "✓ {}",
+            t!("fix_authors.nothing", plan.profile_name, plan.profile.email)
+        );
+        return;
+    }
+    println!(
+        "{}",
+        t!(
+            "fix_authors.header",
+            plan.profile.name,
+            plan.profile.email,
+            plan.profile_name
+        )
+    );
+    for commit in &plan.mismatched {
+        println!(
+            "  {} {} ({} <{}>)",
+            history::short(&commit.commit),
+            commit.subject,
+            commit.author_name,
+            commit.author_email
+        );
+    }
+    if !plan.pushed.is_empty() {
+        println!("⚠️  {}", t!("fix_authors.pushed", plan.pushed.len()));
+    }
+}
+
+/// 활동 기록 출력 (오래된 것부터)
+fn print_activity(entries: &[ActivityEntry]) {
+    if entries.is_empty() {
+        println!("{}", t!("log.empty"));
+        return;
+    }
+    for entry in entries {
+        let mut target = entry.profile.clone().unwrap_or_else(|| "-".to_string());
+        match (&entry.account, entry.event) {
+            (Some(account), _) => target.push_str(&format!(" ({})", account)),
+            (None, ActivityEvent::CredentialErase) => {
+                target.push_str(&format!(" ({})", t!("log.all_accounts")))
             }
-            Err(git_switcher::core::Error::Other(
-                "GitHub API 응답 파싱 실패".to_string(),
-            ))
+            _ => {}
+        }
+        println!(
+            "{} {} {:<16} {}  {}",
+            entry.timestamp,
+            if entry.success { "✓" } else { "❌" },
+            entry.event.as_str(),
+            target,
+            entry.repo.as_deref().unwrap_or("-")
+        );
+        if let Some(command) = &entry.command {
+            println!("    $ {}", command);
+        }
+        for (name, url) in &entry.remotes {
+            println!("    {} {}", name, url);
+        }
+        if let Some(error) = &entry.error {
+            println!("    {}", t!("log.error", error.message));
+        }
+    }
+}
+
+/// `mailmap`이 만든 매핑 출력
+fn print_mailmap(update: &MailmapUpdate) {
+    println!(
+        "{}",
+        t!(
+            "mailmap.header",
+            update.canonical_name,
+            update.canonical_email
+        )
+    );
+    for entry in &update.entries {
+        println!("  {} ({})", entry.email, entry.profile);
+    }
+    for entry in &update.skipped {
+        println!("💡 {}", t!("mailmap.skipped", entry.email));
+    }
+}
+
+/// `import`가 찾은 프로필과 매핑 제안 출력
+fn print_import_plan(plan: &ImportPlan) {
+    if plan.is_empty() {
+        println!("{}", t!("import.nothing"));
+    }
+    if !plan.profiles.is_empty() {
+        println!("{}", t!("import.profiles"));
+        for imported in &plan.profiles {
+            let profile = &imported.profile;
+            println!("  {} ({})", imported.name, profile.email);
+            println!("    {}", t!("field.name", profile.name));
+            if let Some(key) = &profile.signingkey {
+                println!("    {}", t!("field.gpg_key", key));
+            }
+            if let Some(key) = &profile.ssh_key {
+                println!("    {}", t!("field.ssh_key", key));
+            }
+            println!("    {}", t!("import.sources", imported.sources.join(", ")));
+        }
+    }
+    if !plan.path_mappings.is_empty() {
+        println!("{}", t!("import.mappings"));
+        for mapping in &plan.path_mappings {
+            println!(
+                "  {} -> {} ({})",
+                mapping.path, mapping.profile, mapping.source
+            );
+        }
+    }
+    for skipped in &plan.skipped {
+        println!(
+            "💡 {}",
+            t!("import.skipped", skipped.email, skipped.profile)
+        );
+    }
+}
+
+/// `import-bundle`의 항목별 병합 결과 출력
+fn print_merge_report(merge: &MergeReport) {
+    let outcome = |outcome: MergeOutcome| match outcome {
+        MergeOutcome::Added => t!("bundle.added"),
+        MergeOutcome::Unchanged => t!("bundle.unchanged"),
+        MergeOutcome::Skipped => t!("bundle.skipped"),
+        MergeOutcome::Overwritten => t!("bundle.overwritten"),
+        MergeOutcome::Renamed => t!("bundle.renamed"),
+    };
+
+    println!("{}", t!("bundle.profiles"));
+    for entry in &merge.profiles {
+        if entry.outcome == MergeOutcome::Renamed {
+            println!(
+                "  {} -> {} ({})",
+                entry.name,
+                entry.target,
+                outcome(entry.outcome)
+            );
+        } else {
+            println!("  {} ({})", entry.name, outcome(entry.outcome));
+        }
+    }
+    if !merge.path_mappings.is_empty() {
+        println!("{}", t!("bundle.mappings"));
+        for entry in &merge.path_mappings {
+            println!(
+                "  {} -> {} ({})",
+                entry.name,
+                entry.target,
+                outcome(entry.outcome)
+            );
+        }
+    }
+    if merge.tokens > 0 {
+        println!("{}", t!("bundle.tokens", merge.tokens));
+    }
+    if merge.tokens_skipped > 0 {
+        println!("💡 {}", t!("bundle.tokens_skipped", merge.tokens_skipped));
+    }
+}
+
+/// 실제 적용된 신원과 경로 규칙이 기대하는 프로필의 비교 결과 출력
+fn print_status(report: &StatusReport) {
+    println!("📂 {}", t!("status.repository", report.repo_path));
+    println!();
+    println!("{}", t!("status.applied_settings"));
+    for value in &report.identity {
+        println!("  {}", value.key);
+        if value.entries.is_empty() {
+            println!("      {}", t!("status.not_set"));
+        }
+        for (index, entry) in value.entries.iter().rev().enumerate() {
+            let marker = if index == 0 { "→" } else { " " };
+            let scope = if entry.included {
+                format!("{} (include)", entry.scope)
+            } else {
+                entry.scope.clone()
+            };
+            println!(
+                "    {} {}  [{}: {}]",
+                marker, entry.value, scope, entry.origin
+            );
+        }
+    }
+    println!();
+
+    println!(
+        "{}",
+        t!(
+            "status.current_profile",
+            report
+                .current_profile
+                .clone()
+                .unwrap_or_else(|| t!("status.no_matching_profile"))
+        )
+    );
+    println!(
+        "{}",
+        t!(
+            "status.expected_profile",
+            report
+                .expected_profile
+                .clone()
+                .unwrap_or_else(|| t!("status.no_mapping"))
+        )
+    );
+
+    if !report.checks.is_empty() {
+        println!();
+        println!("{}", t!("status.checks"));
+        for check in &report.checks {
+            let mark = if check.passed { "✓" } else { "❌" };
+            println!("  {} {}: {}", mark, check.name, check.detail);
+        }
+    }
+
+    println!();
+    println!("{}", t!("status.result", report.verdict));
+}
+
+/// 프로필 필드를 정렬해서 출력 (출처가 있으면 오른쪽에 표시)
+fn print_profile_fields(profile: &Profile, origin_of: impl Fn(&str) -> Option<String>) {
+    let fields = profile.display_fields();
+    let key_width = fields.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+    let value_width = fields
+        .iter()
+        .map(|(_, value)| value.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    for (key, value) in &fields {
+        match origin_of(key) {
+            Some(origin) => println!("  {:key_width$} = {:value_width$}  {}", key, value, origin),
+            None => println!("  {:key_width$} = {}", key, value),
         }
-        Err(_) => Err(git_switcher::core::Error::Other(
-            "curl 명령 실행 실패".to_string(),
-        )),
     }
 }
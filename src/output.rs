@@ -0,0 +1,175 @@
+use crate::core::{Error, Profile, Result};
+use crate::report::{Event, EventKind, Reporter};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// `--format` 옵션으로 선택하는 출력 형식
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// `--quiet`/`--verbose` 옵션으로 선택하는 출력량
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Verbosity {
+    /// 경고와 실패만 출력
+    Quiet,
+    #[default]
+    Normal,
+    /// 실행한 git 명령 등 상세 이벤트까지 출력
+    Verbose,
+}
+
+impl Verbosity {
+    fn shows(self, kind: EventKind) -> bool {
+        match self {
+            Verbosity::Quiet => matches!(kind, EventKind::Warning | EventKind::Failure),
+            Verbosity::Normal => kind != EventKind::Debug,
+            Verbosity::Verbose => true,
+        }
+    }
+}
+
+/// 출력 형식과 출력량에 맞는 리포터 생성
+pub fn reporter(format: OutputFormat, verbosity: Verbosity) -> Box<dyn Reporter> {
+    match (format, verbosity) {
+        (OutputFormat::Json, _) => Box::new(JsonReporter { verbosity }),
+        (OutputFormat::Text, Verbosity::Quiet) => Box::new(QuietReporter),
+        (OutputFormat::Text, Verbosity::Normal) => Box::new(HumanReporter),
+        (OutputFormat::Text, Verbosity::Verbose) => Box::new(VerboseReporter),
+    }
+}
+
+/// 이벤트 종류별 아이콘을 붙여 출력 (경고와 실패는 stderr, 나머지는 stdout)
+pub struct HumanReporter;
+
+impl HumanReporter {
+    fn render(event: &Event) -> String {
+        let icon = match event.kind {
+            EventKind::Debug => "· ",
+            EventKind::Info => "",
+            EventKind::Step => "🔧 ",
+            EventKind::Success => "✓ ",
+            EventKind::Hint => "💡 ",
+            EventKind::Warning => "⚠️  ",
+            EventKind::Failure => "❌ ",
+        };
+        if event.message.is_empty() {
+            return String::new();
+        }
+        format!("{}{}{}", "  ".repeat(event.depth), icon, event.message)
+    }
+
+    /// 경고와 실패는 stdout을 파이프로 넘겨도 보이도록 stderr로 출력
+    fn print(event: &Event) {
+        if matches!(event.kind, EventKind::Warning | EventKind::Failure) {
+            eprintln!("{}", Self::render(event));
+        } else {
+            println!("{}", Self::render(event));
+        }
+    }
+}
+
+impl Reporter for HumanReporter {
+    fn report(&self, event: &Event) {
+        if event.kind != EventKind::Debug {
+            Self::print(event);
+        }
+    }
+}
+
+/// 경고와 실패만 stderr에 출력
+pub struct QuietReporter;
+
+impl Reporter for QuietReporter {
+    fn report(&self, event: &Event) {
+        if matches!(event.kind, EventKind::Warning | EventKind::Failure) {
+            eprintln!("{}", HumanReporter::render(event));
+        }
+    }
+}
+
+/// 상세 이벤트까지 포함해 출력 (경고와 실패는 stderr)
+pub struct VerboseReporter;
+
+impl Reporter for VerboseReporter {
+    fn report(&self, event: &Event) {
+        HumanReporter::print(event);
+    }
+}
+
+/// stdout을 JSON 결과 전용으로 두고, 이벤트는 한 줄에 하나씩 JSON으로 stderr에 출력
+pub struct JsonReporter {
+    verbosity: Verbosity,
+}
+
+impl Reporter for JsonReporter {
+    fn report(&self, event: &Event) {
+        // 빈 줄은 사람이 읽는 출력에서만 의미가 있음
+        if event.message.is_empty() || !self.verbosity.shows(event.kind) {
+            return;
+        }
+        if let Ok(line) = serde_json::to_string(event) {
+            eprintln!("{}", line);
+        }
+    }
+}
+
+/// 값을 JSON으로 stdout에 출력
+pub fn print_json<T: Serialize>(value: &T) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+/// JSON 출력용 프로필 (PAT는 마스킹)
+#[derive(Serialize)]
+pub struct ProfileView {
+    pub user_name: String,
+    pub email: String,
+    pub extends: Option<String>,
+    pub signing_key: Option<String>,
+    pub ssh_key: Option<String>,
+    pub github_username: Option<String>,
+    pub pat: Option<String>,
+    pub git_config: BTreeMap<String, Vec<String>>,
+}
+
+impl ProfileView {
+    pub fn new(profile: &Profile) -> Self {
+        ProfileView {
+            user_name: profile.name.clone(),
+            email: profile.email.clone(),
+            extends: profile.extends.clone(),
+            signing_key: profile.signingkey.clone(),
+            ssh_key: profile.ssh_key.clone(),
+            github_username: profile.github_username.clone(),
+            pat: profile.get_masked_pat(),
+            git_config: profile.git_config_entries().into_iter().collect(),
+        }
+    }
+}
+
+/// JSON 출력용 오류 (`code`와 `exit_code`는 `Error` 변형에서 결정)
+#[derive(Serialize)]
+pub struct ErrorView {
+    pub code: &'static str,
+    pub exit_code: i32,
+    pub message: String,
+    pub causes: Vec<String>,
+    pub hint: Option<String>,
+}
+
+impl ErrorView {
+    pub fn new(error: &Error) -> Self {
+        ErrorView {
+            code: error.code(),
+            exit_code: error.exit_code(),
+            message: error.to_string(),
+            causes: error.causes(),
+            hint: error.hint(),
+        }
+    }
+}
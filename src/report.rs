@@ -0,0 +1,73 @@
+use serde::Serialize;
+use std::sync::RwLock;
+
+/// 라이브러리가 보내는 진행 상황 이벤트의 종류
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventKind {
+    /// 내부 동작 상세 (실행한 git 명령 등)
+    Debug,
+    Info,
+    /// 시작한 작업
+    Step,
+    Success,
+    /// 다음에 할 일 안내
+    Hint,
+    Warning,
+    /// 작업 전체를 중단하지 않는 개별 실패
+    Failure,
+}
+
+/// 진행 상황 이벤트
+#[derive(Clone, Debug, Serialize)]
+pub struct Event {
+    pub kind: EventKind,
+    /// 앞선 이벤트에 딸린 항목이면 1 이상
+    pub depth: usize,
+    pub message: String,
+}
+
+/// 이벤트를 화면 등에 출력하는 방법
+///
+/// 라이브러리는 직접 출력하지 않고 `set_reporter`로 등록된 리포터에 이벤트를 보냄
+pub trait Reporter: Send + Sync {
+    fn report(&self, event: &Event);
+}
+
+static REPORTER: RwLock<Option<Box<dyn Reporter>>> = RwLock::new(None);
+
+/// 프로세스 전체의 리포터 등록 (등록하지 않으면 이벤트는 버려짐)
+pub fn set_reporter(reporter: Box<dyn Reporter>) {
+    if let Ok(mut current) = REPORTER.write() {
+        *current = Some(reporter);
+    }
+}
+
+pub fn emit(kind: EventKind, depth: usize, message: String) {
+    if let Ok(current) = REPORTER.read()
+        && let Some(reporter) = current.as_ref()
+    {
+        reporter.report(&Event {
+            kind,
+            depth,
+            message,
+        });
+    }
+}
+
+/// 진행 상황 이벤트 보고
+///
+/// `report!(Success, "...")`, 딸린 항목은 `report!(Hint(1), "...")`처럼 깊이를 지정.
+/// 빈 메시지(`report!(Info)`)는 구분용 빈 줄
+#[macro_export]
+macro_rules! report {
+    ($kind:ident) => {
+        $crate::report::emit($crate::report::EventKind::$kind, 0, String::new())
+    };
+    ($kind:ident($depth:expr), $($arg:tt)*) => {
+        $crate::report::emit($crate::report::EventKind::$kind, $depth, format!($($arg)*))
+    };
+    ($kind:ident, $($arg:tt)*) => {
+        $crate::report::emit($crate::report::EventKind::$kind, 0, format!($($arg)*))
+    };
+}
@@ -1,12 +1,28 @@
 use crate::{
-    core::{Config, Error, ProfileManager, Result},
+    core::{Config, Error, Profile, ProfileManager, Result, policy},
     git::GitConfig,
+    report, t,
 };
 
+/// 하위 모듈 하나에 프로필을 적용한 결과
+pub struct SubmoduleResult {
+    pub path: String,
+    /// 적용한(실패했으면 적용하려던) 프로필
+    pub profile: String,
+    /// 실패했으면 그 오류
+    pub error: Option<Error>,
+}
+
 pub struct AutoDetector;
 
 impl AutoDetector {
-    pub fn detect_and_apply_profile(enable_ssh: bool) -> Result<()> {
+    /// 경로 매핑으로 프로필을 찾아 적용하고, 적용한 프로필 이름과 병합된 프로필을 반환
+    ///
+    /// `per_worktree`면 `extensions.worktreeConfig`를 켜고 현재 작업 트리에만 적용
+    pub fn detect_and_apply_profile(
+        enable_ssh: bool,
+        per_worktree: bool,
+    ) -> Result<(String, Profile)> {
         if !GitConfig::is_git_repo() {
             return Err(Error::NotGitRepo);
         }
@@ -14,33 +30,42 @@ impl AutoDetector {
         let config = Config::load()?;
         let current_path = GitConfig::get_current_directory()?;
 
-        if let Some(profile_name) = config.find_profile_for_path(&current_path) {
-            let profile = config.get_profile(profile_name)?;
-            println!("🔍 자동 감지된 프로필: {}", profile_name);
-            println!("📂 경로: {}", current_path);
-
-            ProfileManager::apply_profile(profile, enable_ssh)?;
+        if let Some((mapped_path, profile_name)) = config.find_mapping_for_path(&current_path) {
+            if !config.profiles.contains_key(profile_name) {
+                return Err(Error::InvalidMapping(
+                    mapped_path.clone(),
+                    profile_name.clone(),
+                ));
+            }
+            let profile = config.resolve_profile(profile_name)?;
+            config.check_repo_policy(profile_name, &profile)?;
+            policy::enforce_for_profile(&config, &profile, enable_ssh)?;
+            report!(Info, "{}", t!("auto.detected", profile_name));
+            report!(Info, "{}", t!("auto.path", current_path));
+
+            if per_worktree {
+                GitConfig::enable_worktree_config()?;
+            }
+            ProfileManager::apply_profile(profile_name, &profile, enable_ssh)?;
 
-            Ok(())
+            Ok((profile_name.clone(), profile))
         } else {
-            println!("❌ 현재 경로에 매핑된 프로필이 없습니다.");
-            println!("📂 현재 경로: {}", current_path);
-            println!();
-            println!("사용 가능한 경로 매핑:");
+            report!(Info, "{}", t!("auto.available_mappings"));
 
             if config.path_mappings.is_empty() {
-                println!("  (매핑된 경로가 없습니다)");
-                println!();
-                println!("경로 매핑을 추가하려면:");
-                println!("  git-switcher map <경로> <프로필>");
-                println!("  예: git-switcher map ~/workspace/company company");
+                report!(Info(1), "{}", t!("auto.no_mappings"));
+                report!(Info);
+                report!(Info, "{}", t!("auto.how_to_map"));
+                report!(Info(1), "{}", t!("auto.map_usage"));
+                report!(Info(1), "{}", t!("auto.map_example"));
             } else {
                 for (path, profile) in &config.path_mappings {
-                    println!("  {} -> {}", path, profile);
+                    report!(Info(1), "{} -> {}", path, profile);
                 }
             }
 
-            Err(Error::Other("매핑된 프로필이 없습니다".to_string()))
+            report!(Info);
+            Err(Error::NoMappedProfile(current_path))
         }
     }
 
@@ -64,41 +89,53 @@ impl AutoDetector {
     }
 
     pub fn apply_to_all_mapped_repos(enable_ssh: bool) -> Result<()> {
-        let config = Config::load()?;
+        let mut config = Config::load()?;
         let repos = Self::find_git_repos_in_mapped_paths(&config)?;
 
         if repos.is_empty() {
-            println!("매핑된 경로에서 Git 저장소를 찾을 수 없습니다.");
+            report!(Info, "{}", t!("auto.no_repos"));
             return Ok(());
         }
 
-        println!("🔍 발견된 Git 저장소들:");
+        report!(Info, "{}", t!("auto.found_repos"));
         for (repo_path, profile_name) in &repos {
-            println!("  {} -> {}", repo_path, profile_name);
+            report!(Info(1), "{} -> {}", repo_path, profile_name);
         }
-        println!();
+        report!(Info);
 
         let current_dir = std::env::current_dir()?;
 
         for (repo_path, profile_name) in repos {
-            println!("📂 처리 중: {}", repo_path);
+            report!(Info, "{}", t!("auto.processing", repo_path));
 
             // 디렉토리 변경
             if let Err(e) = std::env::set_current_dir(&repo_path) {
-                println!("  ❌ 디렉토리 변경 실패: {}", e);
+                report!(Failure(1), "{}", t!("auto.chdir_failed", e));
                 continue;
             }
 
-            // 프로필 적용
-            match config.get_profile(&profile_name) {
-                Ok(profile) => match ProfileManager::apply_profile(profile, enable_ssh) {
-                    Ok(_) => println!("  ✓ {} 프로필 적용 완료", profile_name),
-                    Err(e) => println!("  ❌ 프로필 적용 실패: {}", e),
-                },
-                Err(e) => println!("  ❌ 프로필 로드 실패: {}", e),
+            // 저장소마다 자기 .git-switcher.toml의 제약을 확인한 뒤 프로필 적용
+            let resolved = config.reload_repo_layer().and_then(|_| {
+                let profile = config.resolve_profile(&profile_name)?;
+                config.check_repo_policy(&profile_name, &profile)?;
+                policy::enforce_for_profile(&config, &profile, enable_ssh)?;
+                Ok(profile)
+            });
+            match resolved {
+                Ok(profile) => {
+                    match ProfileManager::apply_profile(&profile_name, &profile, enable_ssh) {
+                        Ok(_) => report!(Success(1), "{}", t!("auto.applied", profile_name)),
+                        Err(e) => report!(
+                            Failure(1),
+                            "{}",
+                            t!("auto.apply_failed", format!("{:#}", e))
+                        ),
+                    }
+                }
+                Err(e) => report!(Failure(1), "{}", t!("auto.load_failed", format!("{:#}", e))),
             }
 
-            println!();
+            report!(Info);
         }
 
         // 원래 디렉토리로 복귀
@@ -106,13 +143,83 @@ impl AutoDetector {
 
         Ok(())
     }
+
+    /// 현재 저장소의 하위 모듈(중첩 포함)마다 프로필 적용
+    ///
+    /// 하위 모듈의 `.git-switcher.toml`이 지정한 프로필, 하위 모듈 원격 주소의 매핑,
+    /// 상위 저장소에 적용한 프로필 순서로 고르며, 한 하위 모듈이 실패해도 나머지는 계속 적용
+    pub fn apply_to_submodules(
+        config: &mut Config,
+        profile_name: &str,
+        enable_ssh: bool,
+    ) -> Result<Vec<SubmoduleResult>> {
+        let submodules = GitConfig::list_submodules()?;
+        if submodules.is_empty() {
+            report!(Info, "{}", t!("submodule.none"));
+            return Ok(Vec::new());
+        }
+
+        let mut results = Vec::new();
+        for path in submodules {
+            report!(Info, "{}", t!("submodule.processing", path));
+
+            // 이번 반복이 끝나면(중간에 실패해도) 상위 저장소로 돌아감
+            let _work_dir = GitConfig::work_in(&path)?;
+            let mut selected = profile_name.to_string();
+            let applied = config.reload_repo_layer().and_then(|_| {
+                if let Some(required) = config
+                    .repo_layer()
+                    .and_then(|repo| repo.policy.profile.clone())
+                {
+                    selected = required;
+                } else if let Some((pattern, mapped)) = GitConfig::get_remotes()?
+                    .iter()
+                    .find_map(|(_, url)| config.find_mapping_for_remote(url))
+                {
+                    if !config.profiles.contains_key(mapped) {
+                        return Err(Error::InvalidMapping(pattern.clone(), mapped.clone()));
+                    }
+                    selected = mapped.clone();
+                }
+                let profile = config.resolve_profile(&selected)?;
+                config.check_repo_policy(&selected, &profile)?;
+                policy::enforce_for_profile(config, &profile, enable_ssh)?;
+                ProfileManager::apply_profile(&selected, &profile, enable_ssh)
+            });
+
+            let error = match applied {
+                Ok(()) => {
+                    report!(Success(1), "{}", t!("auto.applied", selected));
+                    None
+                }
+                Err(e) => {
+                    report!(
+                        Failure(1),
+                        "{}",
+                        t!("auto.apply_failed", format!("{:#}", e))
+                    );
+                    Some(e)
+                }
+            };
+            results.push(SubmoduleResult {
+                path,
+                profile: selected,
+                error,
+            });
+            report!(Info);
+        }
+
+        // 원래 저장소의 .git-switcher.toml을 다시 읽음
+        config.reload_repo_layer()?;
+        Ok(results)
+    }
 }
 
 fn expand_path(path: &str) -> String {
-    if path.starts_with("~/") {
-        if let Some(home) = dirs::home_dir() {
-            return path.replace("~", &home.to_string_lossy());
-        }
+    if path.starts_with("~/")
+        && let Some(home) = dirs::home_dir()
+    {
+        return path.replace("~", &home.to_string_lossy());
     }
     path.to_string()
 }
@@ -1,7 +1,8 @@
 use crate::core::{Error, Result};
+use crate::t;
 use aes_gcm::{
     Aes256Gcm, Nonce,
-    aead::{Aead, AeadCore, KeyInit, OsRng},
+    aead::{Aead, AeadCore, KeyInit, OsRng, rand_core::RngCore},
 };
 use base64::{Engine as _, engine::general_purpose};
 use sha2::{Digest, Sha256};
@@ -27,19 +28,60 @@ fn get_build_key() -> [u8; 32] {
     key
 }
 
+/// 번들 암호에서 키를 유도할 때의 PBKDF2-HMAC-SHA256 반복 횟수
+pub const PASSPHRASE_ITERATIONS: u32 = 600_000;
+
+/// 사용자 암호에서 유도한 키 (다른 빌드로 옮기는 번들의 PAT 암호화용)
+pub struct PassphraseKey([u8; 32]);
+
+impl PassphraseKey {
+    /// PBKDF2-HMAC-SHA256 (RFC 8018)
+    pub fn derive(passphrase: &str, salt: &[u8], iterations: u32) -> Self {
+        let mut key = [0u8; 32];
+        pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, iterations, &mut key);
+        PassphraseKey(key)
+    }
+
+    /// 키 유도에 쓸 임의의 솔트
+    pub fn generate_salt() -> [u8; 16] {
+        let mut salt = [0u8; 16];
+        OsRng.fill_bytes(&mut salt);
+        salt
+    }
+}
+
 pub struct TokenCrypto;
 
 impl TokenCrypto {
     /// PAT를 암호화하여 저장 가능한 문자열로 변환
     pub fn encrypt_token(token: &str) -> Result<String> {
-        let key = get_build_key();
-        let cipher = Aes256Gcm::new_from_slice(&key)
-            .map_err(|e| Error::Other(format!("암호화 키 생성 실패: {}", e)))?;
+        Self::encrypt_with_key(&get_build_key(), token)
+    }
+
+    /// 암호화된 토큰을 복호화
+    pub fn decrypt_token(encrypted_token: &str) -> Result<String> {
+        // 형식은 맞는데 인증 태그가 맞지 않으면 다른 키로 암호화된 데이터
+        Self::decrypt_with_key(&get_build_key(), encrypted_token, Error::KeyMismatch)
+    }
+
+    /// 번들 암호에서 유도한 키로 PAT 암호화
+    pub fn encrypt_with_passphrase(token: &str, key: &PassphraseKey) -> Result<String> {
+        Self::encrypt_with_key(&key.0, token)
+    }
+
+    /// 번들 암호에서 유도한 키로 PAT 복호화 (키가 다르면 `WrongPassphrase`)
+    pub fn decrypt_with_passphrase(encrypted_token: &str, key: &PassphraseKey) -> Result<String> {
+        Self::decrypt_with_key(&key.0, encrypted_token, Error::WrongPassphrase)
+    }
+
+    fn encrypt_with_key(key: &[u8; 32], token: &str) -> Result<String> {
+        let cipher =
+            Aes256Gcm::new_from_slice(key).map_err(|e| Error::Encryption(e.to_string()))?;
 
         let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
         let ciphertext = cipher
             .encrypt(&nonce, token.as_bytes())
-            .map_err(|e| Error::Other(format!("토큰 암호화 실패: {}", e)))?;
+            .map_err(|e| Error::Encryption(e.to_string()))?;
 
         // nonce와 ciphertext를 합쳐서 base64로 인코딩
         let mut encrypted_data = nonce.to_vec();
@@ -48,29 +90,24 @@ impl TokenCrypto {
         Ok(general_purpose::STANDARD.encode(encrypted_data))
     }
 
-    /// 암호화된 토큰을 복호화
-    pub fn decrypt_token(encrypted_token: &str) -> Result<String> {
-        let key = get_build_key();
-        let cipher = Aes256Gcm::new_from_slice(&key)
-            .map_err(|e| Error::Other(format!("암호화 키 생성 실패: {}", e)))?;
+    fn decrypt_with_key(key: &[u8; 32], encrypted_token: &str, mismatch: Error) -> Result<String> {
+        let cipher =
+            Aes256Gcm::new_from_slice(key).map_err(|e| Error::Encryption(e.to_string()))?;
 
         let encrypted_data = general_purpose::STANDARD
             .decode(encrypted_token)
-            .map_err(|e| Error::Other(format!("토큰 디코딩 실패: {}", e)))?;
+            .map_err(|e| Error::Decryption(t!("crypto.decode_failed", e)))?;
 
         if encrypted_data.len() < 12 {
-            return Err(Error::Other("잘못된 암호화 데이터 형식".to_string()));
+            return Err(Error::Decryption(t!("crypto.invalid_format")));
         }
 
         let (nonce_bytes, ciphertext) = encrypted_data.split_at(12);
         let nonce = Nonce::from_slice(nonce_bytes);
 
-        let plaintext = cipher
-            .decrypt(nonce, ciphertext)
-            .map_err(|e| Error::Other(format!("토큰 복호화 실패: {}", e)))?;
+        let plaintext = cipher.decrypt(nonce, ciphertext).map_err(|_| mismatch)?;
 
-        String::from_utf8(plaintext)
-            .map_err(|e| Error::Other(format!("토큰 문자열 변환 실패: {}", e)))
+        String::from_utf8(plaintext).map_err(|e| Error::Decryption(t!("crypto.utf8_failed", e)))
     }
 
     /// 토큰의 유효성 검증 (기본적인 PAT 형식 체크)
@@ -95,3 +132,54 @@ impl TokenCrypto {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn pbkdf2_sha256_matches_rfc_7914() {
+        // RFC 7914 11절 (앞 32바이트)
+        let key = PassphraseKey::derive("passwd", b"salt", 1);
+        assert_eq!(
+            hex(&key.0),
+            "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc"
+        );
+    }
+
+    #[test]
+    fn pbkdf2_sha256_matches_rfc_6070_inputs() {
+        // RFC 6070의 입력(원래는 SHA-1)을 SHA-256으로 계산한 널리 쓰이는 값
+        let key = PassphraseKey::derive("password", b"salt", 4096);
+        assert_eq!(
+            hex(&key.0),
+            "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a"
+        );
+    }
+
+    #[test]
+    fn passphrase_round_trip() {
+        let salt = PassphraseKey::generate_salt();
+        let key = PassphraseKey::derive("correct horse", &salt, 1000);
+        let encrypted = TokenCrypto::encrypt_with_passphrase("ghp_secret", &key).unwrap();
+        assert_eq!(
+            TokenCrypto::decrypt_with_passphrase(&encrypted, &key).unwrap(),
+            "ghp_secret"
+        );
+
+        let wrong = PassphraseKey::derive("wrong horse", &salt, 1000);
+        assert!(matches!(
+            TokenCrypto::decrypt_with_passphrase(&encrypted, &wrong),
+            Err(Error::WrongPassphrase)
+        ));
+
+        let mut tampered = general_purpose::STANDARD.decode(&encrypted).unwrap();
+        *tampered.last_mut().unwrap() ^= 1;
+        let tampered = general_purpose::STANDARD.encode(tampered);
+        assert!(TokenCrypto::decrypt_with_passphrase(&tampered, &key).is_err());
+    }
+}
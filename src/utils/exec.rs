@@ -0,0 +1,118 @@
+use crate::{
+    core::{ActivityLog, Config, Error, Profile, Result, policy},
+    git::{
+        GitConfig,
+        credentials::{ONE_SHOT_HELPERS, PAT_ENV, USERNAME_ENV},
+    },
+    report, t,
+    utils::ssh::SshManager,
+};
+use std::process::Command;
+
+pub struct ExecManager;
+
+impl ExecManager {
+    /// 프로필의 신원과 인증 정보를 넣은 환경 변수
+    ///
+    /// 설정은 `GIT_CONFIG_COUNT`/`GIT_CONFIG_KEY_<n>`/`GIT_CONFIG_VALUE_<n>`으로 넘겨 저장소 설정 파일을
+    /// 바꾸지 않으며, 이미 환경에 있는 `GIT_CONFIG_COUNT` 설정 뒤에 이어서 추가
+    pub fn environment(profile: &Profile) -> Result<Vec<(String, String)>> {
+        let mut vars = vec![
+            ("GIT_AUTHOR_NAME".to_string(), profile.name.clone()),
+            ("GIT_AUTHOR_EMAIL".to_string(), profile.email.clone()),
+            ("GIT_COMMITTER_NAME".to_string(), profile.name.clone()),
+            ("GIT_COMMITTER_EMAIL".to_string(), profile.email.clone()),
+        ];
+        // 훅이나 다른 도구가 `git config user.email`로 읽어도 같은 신원이 보이도록 설정으로도 넘김
+        let mut entries = vec![
+            ("user.name".to_string(), profile.name.clone()),
+            ("user.email".to_string(), profile.email.clone()),
+        ];
+        if let Some(signing_key) = &profile.signingkey {
+            entries.push(("user.signingkey".to_string(), signing_key.clone()));
+        }
+        for (key, values) in profile.git_config_entries() {
+            entries.extend(values.into_iter().map(|value| (key.clone(), value)));
+        }
+
+        if let Some(ssh_key) = &profile.ssh_key {
+            vars.push((
+                "GIT_SSH_COMMAND".to_string(),
+                SshManager::ssh_command(ssh_key),
+            ));
+        }
+        if let Some(username) = &profile.github_username
+            && let Some(pat) = profile.get_decrypted_pat()?
+        {
+            for helper in ONE_SHOT_HELPERS {
+                entries.push(("credential.helper".to_string(), helper.to_string()));
+            }
+            vars.push((USERNAME_ENV.to_string(), username.clone()));
+            vars.push((PAT_ENV.to_string(), pat));
+        }
+
+        let offset = std::env::var("GIT_CONFIG_COUNT")
+            .ok()
+            .and_then(|count| count.parse::<usize>().ok())
+            .unwrap_or(0);
+        for (index, (key, value)) in entries.iter().enumerate() {
+            vars.push((format!("GIT_CONFIG_KEY_{}", offset + index), key.clone()));
+            vars.push((
+                format!("GIT_CONFIG_VALUE_{}", offset + index),
+                value.clone(),
+            ));
+        }
+        vars.push((
+            "GIT_CONFIG_COUNT".to_string(),
+            (offset + entries.len()).to_string(),
+        ));
+        Ok(vars)
+    }
+
+    /// 저장소 제약과 팀 정책을 확인한 뒤 프로필 환경으로 명령을 실행하고 종료 코드를 반환
+    ///
+    /// 저장소 설정과 크리덴셜 저장소는 건드리지 않으며, 실행 결과는 활동 기록에 남김
+    pub fn run(config: &Config, profile_name: &str, command: &[String]) -> Result<i32> {
+        let profile = config.resolve_profile(profile_name)?;
+        if GitConfig::is_git_repo() {
+            config.check_repo_policy(profile_name, &profile)?;
+            policy::enforce_for_profile(config, &profile, profile.ssh_key.is_some())?;
+        }
+        let vars = Self::environment(&profile)?;
+
+        let command_line = command.join(" ");
+        report!(Debug, "{}", t!("exec.running", command_line, profile_name));
+        let result = Self::spawn(command, vars);
+        match &result {
+            Ok(0) => ActivityLog::record_command(profile_name, &command_line, None),
+            Ok(code) => ActivityLog::record_command(
+                profile_name,
+                &command_line,
+                Some(&Error::Other(t!("exec.exit_status", code))),
+            ),
+            Err(e) => ActivityLog::record_command(profile_name, &command_line, Some(e)),
+        }
+        result
+    }
+
+    fn spawn(command: &[String], vars: Vec<(String, String)>) -> Result<i32> {
+        let Some((program, args)) = command.split_first() else {
+            return Err(Error::Other(t!("exec.no_command")));
+        };
+        let status = Command::new(program)
+            .args(args)
+            .envs(vars)
+            .status()
+            .map_err(|e| Error::Other(t!("exec.spawn_failed", program, e)))?;
+
+        // 시그널로 끝났으면 셸과 같은 128 + 시그널 번호
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            if let Some(signal) = status.signal() {
+                return Ok(128 + signal);
+            }
+        }
+        Ok(status.code().unwrap_or(1))
+    }
+}
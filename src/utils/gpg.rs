@@ -0,0 +1,49 @@
+use std::process::Command;
+
+pub struct GpgManager;
+
+/// `gpg --list-secret-keys`로 찾은 서명 키
+pub struct GpgSecretKey {
+    pub key_id: String,
+    pub user_id: String,
+}
+
+impl GpgManager {
+    /// 사용 가능한 GPG 비밀 키 목록 (gpg가 없으면 빈 목록)
+    pub fn list_secret_keys() -> Vec<GpgSecretKey> {
+        let output = match Command::new("gpg")
+            .args(["--list-secret-keys", "--with-colons"])
+            .output()
+        {
+            Ok(output) if output.status.success() => output,
+            _ => return Vec::new(),
+        };
+
+        // sec:...:<키 ID>:... 다음에 오는 첫 uid:...:<사용자 ID>:... 줄을 짝지음
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut keys: Vec<GpgSecretKey> = Vec::new();
+        let mut pending_uid = false;
+
+        for line in stdout.lines() {
+            let fields: Vec<&str> = line.split(':').collect();
+            match fields.first() {
+                Some(&"sec") if fields.len() > 4 => {
+                    keys.push(GpgSecretKey {
+                        key_id: fields[4].to_string(),
+                        user_id: String::new(),
+                    });
+                    pending_uid = true;
+                }
+                Some(&"uid") if pending_uid && fields.len() > 9 => {
+                    if let Some(key) = keys.last_mut() {
+                        key.user_id = fields[9].to_string();
+                    }
+                    pending_uid = false;
+                }
+                _ => {}
+            }
+        }
+
+        keys
+    }
+}
@@ -0,0 +1,544 @@
+use crate::{
+    core::{
+        Config, Error, Profile, Result,
+        config::expand_path,
+        policy::{is_ssh_url, normalize_remote},
+    },
+    git::GitConfig,
+    report, t,
+    utils::ssh::{SshHost, SshManager},
+};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// `--root`를 주지 않았을 때 저장소를 찾아볼 흔한 작업 디렉토리
+const COMMON_ROOTS: &[&str] = &[
+    "~/work",
+    "~/projects",
+    "~/src",
+    "~/code",
+    "~/dev",
+    "~/git",
+    "~/repos",
+    "~/workspace",
+];
+
+/// 개인 계정으로 보는 이메일 도메인 (프로필 이름을 `personal`로 제안)
+const PERSONAL_DOMAINS: &[&str] = &[
+    "gmail.com",
+    "googlemail.com",
+    "outlook.com",
+    "hotmail.com",
+    "icloud.com",
+    "me.com",
+    "naver.com",
+    "daum.net",
+    "kakao.com",
+    "proton.me",
+    "protonmail.com",
+    "users.noreply.github.com",
+];
+
+/// 등록 도메인이 한 단계 더 앞에 있는 2단계 공개 접미사 (`acme.co.kr`의 `co.kr`)
+const TWO_LEVEL_SUFFIXES: &[&str] = &[
+    "co.kr", "or.kr", "ac.kr", "go.kr", "ne.kr", "re.kr", "co.uk", "org.uk", "ac.uk", "gov.uk",
+    "co.jp", "or.jp", "ac.jp", "ne.jp", "com.au", "net.au", "org.au", "edu.au", "com.cn", "com.tw",
+    "com.hk", "com.sg", "com.br", "co.nz", "co.in", "co.za",
+];
+
+/// 가져올 프로필 하나
+#[derive(Serialize)]
+pub struct ImportedProfile {
+    pub name: String,
+    pub profile: Profile,
+    /// 값을 찾은 파일 (`~/.gitconfig`, `~/work/app/.git/config` 등)
+    pub sources: Vec<String>,
+}
+
+/// 가져올 경로 매핑 하나
+#[derive(Serialize)]
+pub struct ImportedMapping {
+    pub path: String,
+    pub profile: String,
+    /// 매핑을 추론한 근거
+    pub source: String,
+}
+
+/// 같은 이메일의 프로필이 이미 있어 새로 만들지 않은 신원
+#[derive(Serialize)]
+pub struct SkippedIdentity {
+    pub email: String,
+    /// 대신 사용하는 기존 프로필
+    pub profile: String,
+}
+
+/// `git-switcher import`가 제안하는 변경 내용
+#[derive(Default, Serialize)]
+pub struct ImportPlan {
+    pub profiles: Vec<ImportedProfile>,
+    pub path_mappings: Vec<ImportedMapping>,
+    pub skipped: Vec<SkippedIdentity>,
+}
+
+impl ImportPlan {
+    /// 추가할 프로필과 매핑이 없는지
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty() && self.path_mappings.is_empty()
+    }
+
+    /// 제안한 프로필과 매핑을 설정에 추가
+    pub fn apply(&self, config: &mut Config) {
+        for imported in &self.profiles {
+            config.add_profile(imported.name.clone(), imported.profile.clone());
+        }
+        for mapping in &self.path_mappings {
+            config.add_path_mapping(mapping.path.clone(), mapping.profile.clone());
+        }
+    }
+}
+
+/// 기존 Git 설정에서 찾은 신원 하나 (이메일로 병합하기 전)
+struct Identity {
+    email: String,
+    name: Option<String>,
+    signingkey: Option<String>,
+    ssh_key: Option<String>,
+    /// 프로필 이름 후보 (`~/.gitconfig-work` -> `work`)
+    name_hint: Option<String>,
+    sources: Vec<String>,
+}
+
+/// 경로 매핑 후보 (프로필 이름이 정해지기 전이라 이메일로 가리킴)
+struct MappingCandidate {
+    path: String,
+    email: String,
+    source: String,
+}
+
+#[derive(Default)]
+struct Scan {
+    identities: Vec<Identity>,
+    mappings: Vec<MappingCandidate>,
+    /// 전역 설정의 `user.name` (includeIf 파일에 이메일만 있을 때 사용)
+    global_name: Option<String>,
+}
+
+pub struct ProfileImporter;
+
+impl ProfileImporter {
+    /// 전역 설정, includeIf 파일, 저장소 로컬 설정, `~/.ssh/config`를 읽어
+    /// 이메일 기준으로 중복을 합친 프로필과 경로 매핑을 제안
+    pub fn scan(config: &Config, roots: &[String]) -> Result<ImportPlan> {
+        let home = dirs::home_dir().ok_or(Error::HomeNotFound)?;
+        let ssh_hosts = SshManager::host_aliases();
+        let mut scan = Scan::default();
+
+        let xdg_config = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+            .unwrap_or_else(|| home.join(".config"));
+        for path in [
+            xdg_config.join("git").join("config"),
+            home.join(".gitconfig"),
+        ] {
+            scan.read_global(&path)?;
+        }
+
+        let roots: Vec<String> = if roots.is_empty() {
+            COMMON_ROOTS.iter().map(|root| root.to_string()).collect()
+        } else {
+            roots.to_vec()
+        };
+        for root in &roots {
+            scan.read_repos(root, &ssh_hosts)?;
+        }
+
+        Ok(scan.into_plan(config, &ssh_hosts))
+    }
+}
+
+impl Scan {
+    /// 전역 설정 파일의 신원과 `includeIf "gitdir:..."`로 포함한 파일의 신원
+    fn read_global(&mut self, path: &Path) -> Result<()> {
+        let entries = GitConfig::list_file_config(path)?;
+        let source = display_path(path);
+
+        if let Some(name) = last_value(&entries, "user.name") {
+            self.global_name.get_or_insert(name);
+        }
+        if let Some(identity) = identity_from(&entries, None, &source) {
+            self.identities.push(identity);
+        }
+
+        for (key, value) in &entries {
+            let Some(condition) = key
+                .strip_prefix("includeif.")
+                .and_then(|rest| rest.strip_suffix(".path"))
+            else {
+                continue;
+            };
+            let include = resolve_relative(value, path);
+            let include_entries = GitConfig::list_file_config(&include)?;
+            let Some(identity) = identity_from(
+                &include_entries,
+                name_hint_from_file(&include),
+                &display_path(&include),
+            ) else {
+                continue;
+            };
+
+            match gitdir_directory(condition, path) {
+                Some(directory) => self.mappings.push(MappingCandidate {
+                    path: directory,
+                    email: identity.email.clone(),
+                    source: format!("includeIf \"{}\" ({})", condition, source),
+                }),
+                None => report!(Debug, "{}", t!("import.condition_skipped", condition)),
+            }
+            self.identities.push(identity);
+        }
+        Ok(())
+    }
+
+    /// 작업 디렉토리 바로 아래 저장소들의 로컬 신원
+    ///
+    /// 모든 저장소가 같은 이메일을 쓰면 작업 디렉토리 전체를, 아니면 저장소마다 매핑
+    fn read_repos(&mut self, root: &str, ssh_hosts: &[SshHost]) -> Result<()> {
+        let Ok(entries) = fs::read_dir(expand_path(root)) else {
+            return Ok(());
+        };
+        let mut repos: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            // .git이 파일인 워크트리와 서브모듈은 원래 저장소에서 읽음
+            .filter(|path| path.join(".git").is_dir())
+            .collect();
+        repos.sort();
+
+        let mut found = Vec::new();
+        for repo in &repos {
+            let config_path = repo.join(".git").join("config");
+            let entries = GitConfig::list_file_config(&config_path)?;
+            let Some(mut identity) = identity_from(&entries, None, &display_path(&config_path))
+            else {
+                continue;
+            };
+            // 원격이 ~/.ssh/config의 별칭을 쓰면 그 별칭의 키를 사용
+            if identity.ssh_key.is_none() {
+                identity.ssh_key = entries
+                    .iter()
+                    .filter(|(key, _)| key.starts_with("remote.") && key.ends_with(".url"))
+                    .find_map(|(_, url)| ssh_key_for_remote(url, ssh_hosts));
+            }
+            found.push((display_path(repo), identity.email.clone()));
+            self.identities.push(identity);
+        }
+
+        let emails: BTreeSet<String> = found
+            .iter()
+            .map(|(_, email)| email.to_lowercase())
+            .collect();
+        if found.len() == repos.len() && emails.len() == 1 {
+            self.mappings.push(MappingCandidate {
+                path: root.trim_end_matches('/').to_string(),
+                email: found[0].1.clone(),
+                source: t!("import.source_root", found.len()),
+            });
+        } else {
+            for (path, email) in found {
+                self.mappings.push(MappingCandidate {
+                    path,
+                    email,
+                    source: t!("import.source_repo"),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn into_plan(self, config: &Config, ssh_hosts: &[SshHost]) -> ImportPlan {
+        // 이메일(대소문자 무시)이 같은 신원은 먼저 찾은 값을 우선해 합침
+        let mut merged: Vec<Identity> = Vec::new();
+        for identity in self.identities {
+            let Some(existing) = merged
+                .iter_mut()
+                .find(|existing| existing.email.eq_ignore_ascii_case(&identity.email))
+            else {
+                merged.push(identity);
+                continue;
+            };
+            existing.name = existing.name.take().or(identity.name);
+            existing.signingkey = existing.signingkey.take().or(identity.signingkey);
+            existing.ssh_key = existing.ssh_key.take().or(identity.ssh_key);
+            existing.name_hint = existing.name_hint.take().or(identity.name_hint);
+            for source in identity.sources {
+                if !existing.sources.contains(&source) {
+                    existing.sources.push(source);
+                }
+            }
+        }
+
+        let mut plan = ImportPlan::default();
+        let mut profile_names: BTreeMap<String, String> = BTreeMap::new();
+        for identity in merged {
+            let key = identity.email.to_lowercase();
+            if let Some((existing, _)) = config
+                .profiles
+                .iter()
+                .find(|(_, profile)| profile.email.eq_ignore_ascii_case(&identity.email))
+            {
+                profile_names.insert(key, existing.clone());
+                plan.skipped.push(SkippedIdentity {
+                    email: identity.email,
+                    profile: existing.clone(),
+                });
+                continue;
+            }
+
+            let base = identity
+                .name_hint
+                .unwrap_or_else(|| name_from_email(&identity.email));
+            let name = unique_name(&base, config, &plan);
+            // 별칭 이름이 github.com-<프로필> 형태면 그 키를 사용
+            let ssh_key = identity.ssh_key.or_else(|| {
+                ssh_hosts
+                    .iter()
+                    .find(|host| {
+                        host.alias
+                            .rsplit_once('-')
+                            .is_some_and(|(_, suffix)| suffix.eq_ignore_ascii_case(&name))
+                    })
+                    .map(|host| host.identity_file.clone())
+            });
+
+            profile_names.insert(key, name.clone());
+            plan.profiles.push(ImportedProfile {
+                name,
+                profile: Profile {
+                    extends: None,
+                    name: identity
+                        .name
+                        .or_else(|| self.global_name.clone())
+                        .unwrap_or_default(),
+                    email: identity.email,
+                    signingkey: identity.signingkey,
+                    ssh_key,
+                    github_username: None,
+                    encrypted_pat: None,
+                    git_config: BTreeMap::new(),
+                },
+                sources: identity.sources,
+            });
+        }
+
+        for candidate in self.mappings {
+            let Some(profile) = profile_names.get(&candidate.email.to_lowercase()) else {
+                continue;
+            };
+            // 같은 경로이거나, 같은 프로필로 매핑된 상위 디렉토리 아래면 추가할 필요 없음
+            let expanded = expand_path(&candidate.path);
+            let expanded = expanded.trim_end_matches('/');
+            let existing = config.path_mappings.iter();
+            let proposed = plan
+                .path_mappings
+                .iter()
+                .map(|mapping| (&mapping.path, &mapping.profile));
+            let already_mapped = existing.chain(proposed).any(|(path, mapped_profile)| {
+                let path = expand_path(path);
+                let path = path.trim_end_matches('/');
+                expanded == path
+                    || (mapped_profile == profile
+                        && expanded
+                            .strip_prefix(path)
+                            .is_some_and(|rest| rest.starts_with('/')))
+            });
+            if already_mapped {
+                continue;
+            }
+            plan.path_mappings.push(ImportedMapping {
+                path: candidate.path,
+                profile: profile.clone(),
+                source: candidate.source,
+            });
+        }
+
+        plan
+    }
+}
+
+/// 설정 항목에서 신원 읽기 (`user.email`이 없으면 `None`, 같은 키는 마지막 값)
+fn identity_from(
+    entries: &[(String, String)],
+    name_hint: Option<String>,
+    source: &str,
+) -> Option<Identity> {
+    let email = last_value(entries, "user.email")?;
+    Some(Identity {
+        email,
+        name: last_value(entries, "user.name"),
+        signingkey: last_value(entries, "user.signingkey"),
+        ssh_key: last_value(entries, "core.sshcommand")
+            .as_deref()
+            .and_then(ssh_key_from_command),
+        name_hint,
+        sources: vec![source.to_string()],
+    })
+}
+
+fn last_value(entries: &[(String, String)], key: &str) -> Option<String> {
+    entries
+        .iter()
+        .rev()
+        .find(|(entry_key, _)| entry_key == key)
+        .map(|(_, value)| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// `core.sshCommand = ssh -i ~/.ssh/id_work -o ...`에서 키 경로
+fn ssh_key_from_command(command: &str) -> Option<String> {
+    let mut words = command.split_whitespace();
+    while let Some(word) = words.next() {
+        if word == "-i" {
+            return words.next().map(|key| key.trim_matches('"').to_string());
+        }
+        if let Some(key) = word.strip_prefix("-i") {
+            return Some(key.trim_matches('"').to_string());
+        }
+    }
+    None
+}
+
+/// `gitdir:~/work/`, `gitdir/i:~/work/**` 조건이 가리키는 디렉토리
+///
+/// 중간에 와일드카드가 있거나 gitdir이 아닌 조건(`onbranch:` 등)은 매핑할 수 없으므로 `None`
+fn gitdir_directory(condition: &str, config_file: &Path) -> Option<String> {
+    let pattern = condition
+        .strip_prefix("gitdir:")
+        .or_else(|| condition.strip_prefix("gitdir/i:"))?;
+    let directory = pattern.trim_end_matches("**").trim_end_matches('/');
+    let directory = directory.strip_suffix("/.git").unwrap_or(directory);
+    if directory.is_empty() || directory.contains(['*', '?', '[']) {
+        return None;
+    }
+    if directory.starts_with("./") {
+        return Some(display_path(&resolve_relative(directory, config_file)));
+    }
+    Some(directory.to_string())
+}
+
+/// include 경로 해석 (`~/`는 홈, 상대 경로는 포함하는 파일 기준)
+fn resolve_relative(path: &str, config_file: &Path) -> PathBuf {
+    let expanded = PathBuf::from(expand_path(path));
+    if expanded.is_absolute() {
+        return expanded;
+    }
+    config_file
+        .parent()
+        .map(|parent| parent.join(path.trim_start_matches("./")))
+        .unwrap_or(expanded)
+}
+
+/// 포함 파일 이름에서 프로필 이름 후보 (`.gitconfig-work`, `work.gitconfig` -> `work`)
+fn name_hint_from_file(path: &Path) -> Option<String> {
+    let file_name = path.file_name()?.to_string_lossy().to_lowercase();
+    let hint = file_name
+        .trim_start_matches('.')
+        .replace("gitconfig", "")
+        .trim_matches(['-', '_', '.'])
+        .to_string();
+    sanitize_name(&hint).filter(|hint| hint != "config" && hint != "inc")
+}
+
+/// 이메일 도메인에서 프로필 이름 후보 (`me@dev.acme.co.kr` -> `acme`)
+fn name_from_email(email: &str) -> String {
+    let domain = email
+        .rsplit_once('@')
+        .map(|(_, domain)| domain.to_lowercase())
+        .unwrap_or_default();
+    if PERSONAL_DOMAINS
+        .iter()
+        .any(|personal| domain == *personal || domain.ends_with(&format!(".{}", personal)))
+    {
+        return "personal".to_string();
+    }
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    let mut index = labels.len().saturating_sub(2);
+    if index > 0 && TWO_LEVEL_SUFFIXES.contains(&labels[index..].join(".").as_str()) {
+        index -= 1;
+    }
+    labels
+        .get(index)
+        .and_then(|label| sanitize_name(label))
+        .unwrap_or_else(|| "imported".to_string())
+}
+
+fn sanitize_name(name: &str) -> Option<String> {
+    let name: String = name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect();
+    Some(name).filter(|name| !name.is_empty())
+}
+
+/// 기존 프로필이나 앞서 제안한 이름과 겹치지 않는 이름 (`work`, `work-2`, ...)
+fn unique_name(base: &str, config: &Config, plan: &ImportPlan) -> String {
+    let taken = |name: &str| {
+        config.profiles.contains_key(name) || plan.profiles.iter().any(|p| p.name == name)
+    };
+    if !taken(base) {
+        return base.to_string();
+    }
+    (2..)
+        .map(|n| format!("{}-{}", base, n))
+        .find(|name| !taken(name))
+        .unwrap_or_default()
+}
+
+/// 원격이 SSH 별칭 호스트를 쓰면 그 별칭의 키
+fn ssh_key_for_remote(url: &str, ssh_hosts: &[SshHost]) -> Option<String> {
+    if !is_ssh_url(url) {
+        return None;
+    }
+    let normalized = normalize_remote(url)?;
+    let host = normalized.split('/').next()?;
+    ssh_hosts
+        .iter()
+        .find(|ssh_host| ssh_host.alias.eq_ignore_ascii_case(host))
+        .map(|ssh_host| ssh_host.identity_file.clone())
+}
+
+/// 홈 디렉토리 아래 경로는 `~/`로 표시
+fn display_path(path: &Path) -> String {
+    if let Some(home) = dirs::home_dir()
+        && let Ok(relative) = path.strip_prefix(&home)
+    {
+        return format!("~/{}", relative.display());
+    }
+    path.display().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_from_email_uses_registrable_domain() {
+        assert_eq!(name_from_email("me@acme.com"), "acme");
+        assert_eq!(name_from_email("me@mail.ibm.com"), "ibm");
+        assert_eq!(name_from_email("me@dev.io.example"), "io");
+        assert_eq!(name_from_email("me@dev.acme.co.kr"), "acme");
+        assert_eq!(name_from_email("me@acme.co.uk"), "acme");
+        assert_eq!(name_from_email("me@acme.com.au"), "acme");
+    }
+
+    #[test]
+    fn name_from_email_falls_back() {
+        assert_eq!(name_from_email("me@gmail.com"), "personal");
+        assert_eq!(name_from_email("1+me@users.noreply.github.com"), "personal");
+        assert_eq!(name_from_email("me@localhost"), "localhost");
+        assert_eq!(name_from_email("nobody"), "imported");
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    core::{Config, Error, Result},
+    git::GitConfig,
+};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// git-switcher가 관리하는 블록의 시작/끝 표시 (이 밖의 줄은 손대지 않음)
+const BLOCK_BEGIN: &str = "# >>> git-switcher mailmap >>>";
+const BLOCK_END: &str = "# <<< git-switcher mailmap <<<";
+
+/// 생성한(또는 건너뛴) 매핑 하나
+#[derive(Serialize)]
+pub struct MailmapEntry {
+    pub email: String,
+    pub profile: String,
+}
+
+/// `.mailmap` 갱신 계획
+pub struct MailmapUpdate {
+    pub path: PathBuf,
+    pub canonical_name: String,
+    pub canonical_email: String,
+    /// 블록에 넣는 매핑
+    pub entries: Vec<MailmapEntry>,
+    /// 직접 적어 둔 줄이 이미 매핑하고 있어 건너뛴 이메일
+    pub skipped: Vec<MailmapEntry>,
+    /// 갱신한 파일 내용
+    pub content: String,
+    /// 기존 파일과 내용이 다른지
+    pub changed: bool,
+}
+
+impl MailmapUpdate {
+    /// 프로필들의 이메일을 기준 프로필의 이름/이메일로 매핑하는 블록을 만들고 파일 내용에 반영
+    ///
+    /// `profiles`가 비어 있으면 모든 프로필, `path`가 없으면 저장소 최상위의 `.mailmap`
+    pub fn plan(
+        config: &Config,
+        path: Option<&Path>,
+        canonical: &str,
+        profiles: &[String],
+    ) -> Result<Self> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => Path::new(&GitConfig::get_repo_root()?).join(".mailmap"),
+        };
+        let canonical_profile = config.resolve_profile(canonical)?;
+        let names: Vec<String> = if profiles.is_empty() {
+            config.profiles.keys().cloned().collect()
+        } else {
+            profiles.to_vec()
+        };
+
+        let existing = if path.exists() {
+            fs::read_to_string(&path)?
+        } else {
+            String::new()
+        };
+        let (before, after) = split_block(&existing);
+        let hand_written: HashSet<String> = before
+            .iter()
+            .chain(after.iter())
+            .filter_map(|line| commit_email(line))
+            .collect();
+
+        // 기준 이메일 자체도 이름을 맞추도록 먼저 넣음
+        let mut candidates = vec![(canonical_profile.email.clone(), canonical.to_string())];
+        for name in &names {
+            let profile = config.resolve_profile(name)?;
+            candidates.push((profile.email, name.clone()));
+        }
+
+        let mut seen = HashSet::new();
+        let mut entries = Vec::new();
+        let mut skipped = Vec::new();
+        for (email, profile) in candidates {
+            if !seen.insert(email.to_lowercase()) {
+                continue;
+            }
+            let entry = MailmapEntry { email, profile };
+            if hand_written.contains(&entry.email.to_lowercase()) {
+                skipped.push(entry);
+            } else {
+                entries.push(entry);
+            }
+        }
+
+        let mut lines: Vec<String> = before.iter().map(|line| line.to_string()).collect();
+        if !entries.is_empty() {
+            if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push(BLOCK_BEGIN.to_string());
+            for entry in &entries {
+                if entry.email.eq_ignore_ascii_case(&canonical_profile.email) {
+                    lines.push(format!(
+                        "{} <{}>",
+                        canonical_profile.name, canonical_profile.email
+                    ));
+                } else {
+                    lines.push(format!(
+                        "{} <{}> <{}>",
+                        canonical_profile.name, canonical_profile.email, entry.email
+                    ));
+                }
+            }
+            lines.push(BLOCK_END.to_string());
+        }
+        lines.extend(after.iter().map(|line| line.to_string()));
+
+        let mut content = lines.join("\n");
+        if !content.is_empty() {
+            content.push('\n');
+        }
+        Ok(MailmapUpdate {
+            path,
+            canonical_name: canonical_profile.name,
+            canonical_email: canonical_profile.email,
+            entries,
+            skipped,
+            changed: content != existing,
+            content,
+        })
+    }
+
+    /// 갱신한 내용을 파일에 씀
+    pub fn write(&self) -> Result<()> {
+        fs::write(&self.path, &self.content).map_err(|e| {
+            Error::Io(std::io::Error::new(
+                e.kind(),
+                format!("{}: {}", self.path.display(), e),
+            ))
+        })
+    }
+}
+
+/// 파일 줄을 관리 블록 앞과 뒤로 나눔 (블록이 없으면 모두 앞)
+///
+/// 블록을 넣을 자리에 붙어 있던 빈 줄은 블록을 다시 넣을 때 새로 붙이므로 제거
+fn split_block(content: &str) -> (Vec<&str>, Vec<&str>) {
+    let lines: Vec<&str> = content.lines().collect();
+    let begin = lines.iter().position(|line| line.trim() == BLOCK_BEGIN);
+    let end = lines.iter().position(|line| line.trim() == BLOCK_END);
+    match (begin, end) {
+        (Some(begin), Some(end)) if begin < end => {
+            let mut before = lines[..begin].to_vec();
+            while before.last().is_some_and(|line| line.trim().is_empty()) {
+                before.pop();
+            }
+            (before, lines[end + 1..].to_vec())
+        }
+        _ => (lines, Vec::new()),
+    }
+}
+
+/// `.mailmap` 한 줄이 바꾸는 커밋 이메일 (마지막 `<...>`, 주석이나 빈 줄이면 `None`)
+fn commit_email(line: &str) -> Option<String> {
+    let line = line.split('#').next()?.trim();
+    let start = line.rfind('<')?;
+    let end = start + line[start..].find('>')?;
+    Some(line[start + 1..end].trim().to_lowercase())
+}
@@ -1,7 +1,21 @@
 pub mod auto;
+pub mod clone;
 pub mod crypto;
+pub mod exec;
+pub mod gpg;
+pub mod import;
+pub mod mailmap;
+pub mod picker;
 pub mod ssh;
+pub mod wizard;
 
 pub use auto::AutoDetector;
+pub use clone::CloneManager;
 pub use crypto::TokenCrypto;
+pub use exec::ExecManager;
+pub use gpg::GpgManager;
+pub use import::ProfileImporter;
+pub use mailmap::MailmapUpdate;
+pub use picker::ProfilePicker;
 pub use ssh::SshManager;
+pub use wizard::ProfileWizard;
@@ -0,0 +1,110 @@
+use crate::{
+    core::{Config, Error, Result},
+    git::GitConfig,
+    t,
+};
+use dialoguer::FuzzySelect;
+use std::io::{BufRead, IsTerminal, Write};
+
+pub struct ProfilePicker;
+
+impl ProfilePicker {
+    /// 프로필 목록에서 하나를 골라 이름을 반환
+    ///
+    /// 터미널이면 퍼지 검색 선택기를, 아니면 번호 입력 프롬프트를 사용
+    pub fn pick(config: &Config) -> Result<String> {
+        let names: Vec<&String> = config.profiles.keys().collect();
+        if names.is_empty() {
+            return Err(Error::NoProfiles);
+        }
+
+        let current_dir = GitConfig::get_current_directory()?;
+        let suggested = config.find_profile_for_path(&current_dir);
+        let applied = Self::find_applied_profile(config);
+
+        let items: Vec<String> = names
+            .iter()
+            .map(|name| {
+                let mut item = match config.resolve_profile(name) {
+                    Ok(profile) => format!("{}: {}", name, profile.summary()),
+                    Err(e) => format!("{}: ❌ {}", name, e),
+                };
+                if applied == Some(*name) {
+                    item.push_str(&format!("  ● {}", t!("picker.applied")));
+                }
+                if suggested == Some(*name) {
+                    item.push_str(&format!("  ★ {}", t!("picker.mapped")));
+                }
+                item
+            })
+            .collect();
+
+        // 경로 매핑으로 추천되는 프로필, 없으면 현재 적용된 프로필에 커서를 둠
+        let default = suggested
+            .or(applied)
+            .and_then(|target| names.iter().position(|name| *name == target));
+
+        let index = if std::io::stdin().is_terminal() && std::io::stderr().is_terminal() {
+            FuzzySelect::new()
+                .with_prompt(t!("picker.prompt"))
+                .items(&items)
+                .default(default.unwrap_or(0))
+                .interact()?
+        } else {
+            Self::prompt_numbered(&names, &items, default)?
+        };
+
+        Ok(names[index].clone())
+    }
+
+    /// 터미널이 아닐 때 사용하는 번호 선택 프롬프트 (번호나 프로필 이름 입력)
+    ///
+    /// 빈 줄은 추천 프로필이 있을 때만 그 프로필로 받고, 입력이 끝나면(EOF) 아무것도 고르지 않음
+    fn prompt_numbered(
+        names: &[&String],
+        items: &[String],
+        default: Option<usize>,
+    ) -> Result<usize> {
+        // 선택 화면은 결과 출력과 섞이지 않도록 FuzzySelect처럼 stderr에 표시
+        eprintln!("{}", t!("picker.available"));
+        for (index, item) in items.iter().enumerate() {
+            eprintln!("  {}) {}", index + 1, item);
+        }
+        match default {
+            Some(default) => eprint!("{} [{}]: ", t!("picker.numbered_prompt"), default + 1),
+            None => eprint!("{}: ", t!("picker.numbered_prompt")),
+        }
+        std::io::stderr().flush()?;
+
+        let mut input = String::new();
+        if std::io::stdin().lock().read_line(&mut input)? == 0 {
+            // CI나 `< /dev/null`처럼 입력이 없으면 임의의 프로필을 적용하지 않음
+            eprintln!();
+            return Err(Error::NotInteractive);
+        }
+        let input = input.trim();
+
+        if input.is_empty() {
+            return default.ok_or(Error::NoSelection);
+        }
+        if let Ok(number) = input.parse::<usize>()
+            && (1..=names.len()).contains(&number)
+        {
+            return Ok(number - 1);
+        }
+        names
+            .iter()
+            .position(|name| name.as_str() == input)
+            .ok_or_else(|| Error::ProfileNotFound(input.to_string()))
+    }
+
+    /// 현재 저장소의 로컬 `user.name`/`user.email`과 일치하는 프로필
+    fn find_applied_profile(config: &Config) -> Option<&String> {
+        if !GitConfig::is_git_repo() {
+            return None;
+        }
+        let name = GitConfig::get_user_name().ok()?;
+        let email = GitConfig::get_user_email().ok()?;
+        config.find_profile_by_identity(&name, &email)
+    }
+}
@@ -1,5 +1,15 @@
 use crate::core::Profile;
-use std::collections::HashMap;
+use crate::core::config::expand_path;
+use crate::t;
+use std::collections::BTreeMap;
+
+/// `~/.ssh/config`에서 `IdentityFile`이 지정된 호스트 별칭
+pub struct SshHost {
+    pub alias: String,
+    /// `HostName` (없으면 별칭이 곧 호스트)
+    pub hostname: Option<String>,
+    pub identity_file: String,
+}
 
 pub struct SshManager;
 
@@ -7,7 +17,7 @@ impl SshManager {
     fn extract_ssh_config_name(ssh_key_path: &str) -> String {
         // SSH 키 경로에서 설정 이름 추출
         // 예: ~/.ssh/id_ed25519_personal -> personal
-        if let Some(filename) = ssh_key_path.split('/').last() {
+        if let Some(filename) = ssh_key_path.split('/').next_back() {
             if let Some(name) = filename.strip_prefix("id_ed25519_") {
                 return name.to_string();
             }
@@ -19,27 +29,133 @@ impl SshManager {
         // 기본값으로 키 파일명 사용
         ssh_key_path
             .split('/')
-            .last()
+            .next_back()
             .unwrap_or("default")
             .to_string()
     }
 
-    pub fn generate_ssh_config_example(profiles: &HashMap<String, Profile>) -> String {
+    /// `~/.ssh/id_*` 개인 키 목록 (`~/` 형태 경로)
+    pub fn list_private_keys() -> Vec<String> {
+        let Some(ssh_dir) = dirs::home_dir().map(|home| home.join(".ssh")) else {
+            return Vec::new();
+        };
+        let Ok(entries) = std::fs::read_dir(&ssh_dir) else {
+            return Vec::new();
+        };
+
+        let mut keys: Vec<String> = entries
+            .flatten()
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|name| name.starts_with("id_") && !name.ends_with(".pub"))
+            .map(|name| format!("~/.ssh/{}", name))
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    /// `~/.ssh/config`의 `Host` 블록 중 와일드카드가 없고 `IdentityFile`이 있는 별칭
+    pub fn host_aliases() -> Vec<SshHost> {
+        let Some(content) = dirs::home_dir()
+            .and_then(|home| std::fs::read_to_string(home.join(".ssh").join("config")).ok())
+        else {
+            return Vec::new();
+        };
+
+        let mut hosts = Vec::new();
+        let mut aliases: Vec<String> = Vec::new();
+        let mut hostname = None;
+        let mut identity_file = None;
+        let mut flush = |aliases: &mut Vec<String>,
+                         hostname: &mut Option<String>,
+                         identity_file: &mut Option<String>| {
+            if let Some(identity_file) = identity_file.take() {
+                for alias in aliases.drain(..) {
+                    hosts.push(SshHost {
+                        alias,
+                        hostname: hostname.clone(),
+                        identity_file: identity_file.clone(),
+                    });
+                }
+            }
+            aliases.clear();
+            *hostname = None;
+        };
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (keyword, value) = line
+                .split_once(|c: char| c.is_whitespace() || c == '=')
+                .map(|(keyword, value)| {
+                    (keyword, value.trim_start_matches([' ', '\t', '=']).trim())
+                })
+                .unwrap_or((line, ""));
+            let value = value.trim_matches('"').to_string();
+
+            if keyword.eq_ignore_ascii_case("host") || keyword.eq_ignore_ascii_case("match") {
+                flush(&mut aliases, &mut hostname, &mut identity_file);
+                if keyword.eq_ignore_ascii_case("host") {
+                    aliases = value
+                        .split_whitespace()
+                        .filter(|alias| !alias.contains(['*', '?', '!']))
+                        .map(str::to_string)
+                        .collect();
+                }
+            } else if keyword.eq_ignore_ascii_case("hostname") {
+                hostname = Some(value);
+            } else if keyword.eq_ignore_ascii_case("identityfile") {
+                // 여러 번 지정하면 ssh는 처음 것부터 시도
+                identity_file.get_or_insert(value);
+            }
+        }
+        flush(&mut aliases, &mut hostname, &mut identity_file);
+        hosts
+    }
+
+    /// `host`에 접속하면서 `ssh_key`를 쓰는 별칭 (`github.com-work` 등)
+    pub fn find_alias(host: &str, ssh_key: &str) -> Option<String> {
+        let key = expand_path(ssh_key);
+        Self::host_aliases()
+            .into_iter()
+            .find(|ssh_host| {
+                ssh_host
+                    .hostname
+                    .as_deref()
+                    .is_some_and(|hostname| hostname.eq_ignore_ascii_case(host))
+                    && expand_path(&ssh_host.identity_file) == key
+            })
+            .map(|ssh_host| ssh_host.alias)
+    }
+
+    /// 다른 키 대신 `ssh_key`만 쓰는 ssh 명령 (`core.sshCommand`, `GIT_SSH_COMMAND` 값)
+    pub fn ssh_command(ssh_key: &str) -> String {
+        format!("ssh -i '{}' -o IdentitiesOnly=yes", expand_path(ssh_key))
+    }
+
+    pub fn generate_ssh_config_example(profiles: &BTreeMap<String, Profile>) -> String {
         let mut config = String::new();
-        config.push_str("# ~/.ssh/config 파일에 추가할 설정 예시\n\n");
+        config.push_str(&format!("# {}\n\n", t!("ssh.example_header")));
 
         for (profile_name, profile) in profiles {
             if let Some(ssh_key) = &profile.ssh_key {
                 let config_name = Self::extract_ssh_config_name(ssh_key);
 
-                config.push_str(&format!("# {} 프로필용 GitHub 설정\n", profile_name));
+                config.push_str(&format!(
+                    "# {}\n",
+                    t!("ssh.example_host", profile_name, "GitHub")
+                ));
                 config.push_str(&format!("Host github.com-{}\n", config_name));
                 config.push_str("    HostName github.com\n");
                 config.push_str("    User git\n");
                 config.push_str(&format!("    IdentityFile {}\n", ssh_key));
                 config.push_str("    IdentitiesOnly yes\n\n");
 
-                config.push_str(&format!("# {} 프로필용 GitLab 설정\n", profile_name));
+                config.push_str(&format!(
+                    "# {}\n",
+                    t!("ssh.example_host", profile_name, "GitLab")
+                ));
                 config.push_str(&format!("Host gitlab.com-{}\n", config_name));
                 config.push_str("    HostName gitlab.com\n");
                 config.push_str("    User git\n");
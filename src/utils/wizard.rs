@@ -0,0 +1,239 @@
+use crate::{
+    core::{Config, Error, Profile, Result},
+    git::GitConfig,
+    report, t,
+    utils::{crypto::TokenCrypto, gpg::GpgManager, ssh::SshManager},
+};
+use dialoguer::{Confirm, Input, Password, Select};
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
+
+pub struct ProfileWizard;
+
+/// 마법사에서 입력받은 프로필 (입력하는 동안 설정 파일을 잠그지 않도록 나중에 적용)
+pub struct WizardProfile {
+    pub name: String,
+    pub profile: Profile,
+    /// 프로필에 매핑할 현재 디렉토리
+    pub mapped_dir: Option<String>,
+}
+
+impl WizardProfile {
+    /// 잠금 아래에서 다시 읽은 설정에 추가 (입력하는 사이 같은 이름이 생겼으면 거부)
+    pub fn apply(self, config: &mut Config) -> Result<String> {
+        if config.profiles.contains_key(&self.name) {
+            return Err(Error::ProfileExists(self.name));
+        }
+        if let Some(directory) = self.mapped_dir {
+            config.add_path_mapping(directory, self.name.clone());
+        }
+        config.add_profile(self.name.clone(), self.profile);
+        Ok(self.name)
+    }
+}
+
+impl ProfileWizard {
+    /// 대화형으로 프로필을 입력받음 (`taken`은 이미 쓰고 있는 프로필 이름)
+    pub fn run(taken: &[String]) -> Result<WizardProfile> {
+        if !std::io::stdin().is_terminal() {
+            return Err(Error::NotInteractive);
+        }
+
+        let name: String = Input::new()
+            .with_prompt(t!("wizard.profile_name"))
+            .validate_with(|input: &String| -> std::result::Result<(), String> {
+                if input.trim().is_empty() {
+                    Err(t!("wizard.profile_name_required"))
+                } else if taken.contains(input) {
+                    Err(t!("error.profile_exists", input))
+                } else {
+                    Ok(())
+                }
+            })
+            .interact_text()?;
+
+        // 기본값은 현재 전역 Git 설정에서 가져옴
+        let user_name = Self::prompt_required(
+            &t!("wizard.user_name"),
+            GitConfig::get_global_config("user.name"),
+        )?;
+        let email: String = {
+            let mut input = Input::new().with_prompt(t!("wizard.email"));
+            if let Some(default) = GitConfig::get_global_config("user.email") {
+                input = input.default(default);
+            }
+            input
+                .validate_with(|input: &String| -> std::result::Result<(), String> {
+                    if input.contains('@') {
+                        Ok(())
+                    } else {
+                        Err(t!("wizard.email_invalid"))
+                    }
+                })
+                .interact_text()?
+        };
+        let mut github_username = Self::prompt_optional(&t!("wizard.github_username"), None)?;
+        let ssh_key = Self::select_ssh_key()?;
+        let signingkey = Self::select_signing_key()?;
+
+        let mut profile = Profile {
+            extends: None,
+            name: user_name,
+            email,
+            signingkey,
+            ssh_key,
+            github_username: None,
+            encrypted_pat: None,
+            git_config: BTreeMap::new(),
+        };
+
+        let pat = Password::new()
+            .with_prompt(t!("wizard.github_pat"))
+            .allow_empty_password(true)
+            .interact()?;
+        if !pat.is_empty() {
+            if !TokenCrypto::validate_github_pat(&pat) {
+                report!(Warning, "{}", t!("pat.format_warning"));
+            }
+
+            if Self::confirm(&t!("wizard.verify_pat"), true)? {
+                match GitConfig::test_github_pat(github_username.as_deref().unwrap_or(""), &pat) {
+                    Ok(true) => {
+                        report!(Success, "{}", t!("pat.valid"));
+                        if github_username.is_none()
+                            && let Ok(detected) = GitConfig::detect_github_username(&pat)
+                        {
+                            report!(Success, "{}", t!("pat.username_detected", detected));
+                            github_username = Some(detected);
+                        }
+                    }
+                    _ => report!(Warning, "{}", t!("wizard.pat_unverified")),
+                }
+            }
+
+            profile.set_encrypted_pat(&pat)?;
+            report!(
+                Success,
+                "{}",
+                t!("pat.stored", TokenCrypto::mask_token(&pat))
+            );
+        }
+        profile.github_username = github_username;
+
+        let current_dir = GitConfig::get_current_directory()?;
+        let mapped_dir = Self::confirm(&t!("wizard.map_current_dir", current_dir), false)?
+            .then_some(current_dir);
+
+        Ok(WizardProfile {
+            name,
+            profile,
+            mapped_dir,
+        })
+    }
+
+    /// 예/아니오 질문
+    pub fn confirm(prompt: &str, default: bool) -> Result<bool> {
+        Ok(Confirm::new()
+            .with_prompt(prompt)
+            .default(default)
+            .interact()?)
+    }
+
+    /// 번들 암호 입력 (`confirm`이면 한 번 더 입력받아 확인)
+    ///
+    /// `GIT_SWITCHER_BUNDLE_PASSPHRASE`가 있으면 묻지 않고 그 값을 사용
+    pub fn prompt_passphrase(confirm: bool) -> Result<String> {
+        if let Ok(passphrase) = std::env::var("GIT_SWITCHER_BUNDLE_PASSPHRASE")
+            && !passphrase.is_empty()
+        {
+            return Ok(passphrase);
+        }
+        if !std::io::stdin().is_terminal() {
+            return Err(Error::NotInteractive);
+        }
+
+        let mut password = Password::new().with_prompt(t!("wizard.passphrase"));
+        if confirm {
+            password = password.with_confirmation(
+                t!("wizard.passphrase_confirm"),
+                t!("wizard.passphrase_mismatch"),
+            );
+        }
+        Ok(password.interact()?)
+    }
+
+    fn prompt_required(prompt: &str, default: Option<String>) -> Result<String> {
+        let mut input = Input::new().with_prompt(prompt);
+        if let Some(default) = default {
+            input = input.default(default);
+        }
+        Ok(input.interact_text()?)
+    }
+
+    fn prompt_optional(prompt: &str, default: Option<String>) -> Result<Option<String>> {
+        let mut input = Input::new().with_prompt(prompt).allow_empty(true);
+        if let Some(default) = default {
+            input = input.default(default);
+        }
+        let value: String = input.interact_text()?;
+        Ok(Some(value.trim().to_string()).filter(|value| !value.is_empty()))
+    }
+
+    /// `~/.ssh/id_*` 키 중에서 선택하거나 직접 입력
+    fn select_ssh_key() -> Result<Option<String>> {
+        let keys = SshManager::list_private_keys();
+        if keys.is_empty() {
+            return Self::prompt_optional(&t!("wizard.ssh_key_path_optional"), None);
+        }
+
+        let mut items = vec![t!("wizard.none")];
+        items.extend(keys.iter().cloned());
+        items.push(t!("wizard.enter_manually"));
+
+        let selection = Select::new()
+            .with_prompt(t!("wizard.ssh_key"))
+            .items(&items)
+            .default(0)
+            .interact()?;
+
+        match selection {
+            0 => Ok(None),
+            index if index == items.len() - 1 => {
+                Self::prompt_optional(&t!("wizard.ssh_key_path"), None)
+            }
+            index => Ok(Some(keys[index - 1].clone())),
+        }
+    }
+
+    /// GPG 비밀 키 중에서 선택 (전역 `user.signingkey`가 기본값)
+    fn select_signing_key() -> Result<Option<String>> {
+        let keys = GpgManager::list_secret_keys();
+        let global_key = GitConfig::get_global_config("user.signingkey");
+        if keys.is_empty() {
+            return Self::prompt_optional(&t!("wizard.signing_key_optional"), global_key);
+        }
+
+        let mut items = vec![t!("wizard.none")];
+        items.extend(
+            keys.iter()
+                .map(|key| format!("{}  {}", key.key_id, key.user_id)),
+        );
+
+        let default = global_key
+            .as_deref()
+            .and_then(|global| {
+                keys.iter()
+                    .position(|key| key.key_id.ends_with(global) || global.ends_with(&key.key_id))
+            })
+            .map(|index| index + 1)
+            .unwrap_or(0);
+
+        let selection = Select::new()
+            .with_prompt(t!("wizard.signing_key"))
+            .items(&items)
+            .default(default)
+            .interact()?;
+
+        Ok((selection > 0).then(|| keys[selection - 1].key_id.clone()))
+    }
+}